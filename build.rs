@@ -0,0 +1,40 @@
+// Builds the C library of the unsafe_ffi lesson with the C compiler of the system.
+// No cc crate, the playground has no dependencies: cc -c and ar are enough for one file.
+// CC and AR may point to other tools, like the cc crate allows it.
+
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+const SOURCE: &str = "src/lessons/c/playground.c";
+
+fn run(command: &mut Command) {
+    let status = command
+        .status()
+        .unwrap_or_else(|e| panic!("cannot run {command:?}: {e}"));
+    if !status.success() {
+        panic!("{command:?} failed with {status}");
+    }
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={SOURCE}");
+    println!("cargo:rerun-if-env-changed=CC");
+    println!("cargo:rerun-if-env-changed=AR");
+
+    let out = PathBuf::from(env::var("OUT_DIR").expect("cargo sets OUT_DIR"));
+    let object = out.join("playground.o");
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    run(Command::new(cc)
+        .args(["-c", "-O2", "-fPIC", "-Wall", "-o"])
+        .arg(&object)
+        .arg(SOURCE));
+    let ar = env::var("AR").unwrap_or_else(|_| "ar".to_string());
+    run(Command::new(ar)
+        .arg("crs")
+        .arg(out.join("libplayground.a"))
+        .arg(&object));
+
+    println!("cargo:rustc-link-search=native={}", out.display());
+    println!("cargo:rustc-link-lib=static=playground");
+}
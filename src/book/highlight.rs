@@ -0,0 +1,97 @@
+// A small highlighter for the code blocks of the book. It knows comments, strings,
+// lifetimes, numbers, keywords, macros and types, which is all the lessons need.
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn is_ident(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+// the code as html, every token that has a colour is a <span class="...">
+pub fn html(code: &str) -> String {
+    let chars: Vec<char> = code.chars().collect();
+    let mut html = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let start = i;
+        let c = chars[i];
+        let class = if c == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            Some("comment")
+        } else if c == '"' {
+            i += 1;
+            while i < chars.len() && chars[i] != '"' {
+                i += if chars[i] == '\\' { 2 } else { 1 };
+            }
+            i = (i + 1).min(chars.len());
+            Some("string")
+        } else if c == '\'' {
+            // 'x' and '\n' are chars, 'a and 'static are lifetimes
+            if chars.get(i + 1) == Some(&'\\') {
+                i += 2;
+                while i < chars.len() && chars[i] != '\'' {
+                    i += 1;
+                }
+                i = (i + 1).min(chars.len());
+                Some("string")
+            } else if chars.get(i + 2) == Some(&'\'') {
+                i += 3;
+                Some("string")
+            } else {
+                i += 1;
+                while i < chars.len() && is_ident(chars[i]) {
+                    i += 1;
+                }
+                Some("lifetime")
+            }
+        } else if c.is_ascii_digit() {
+            // 123u8, 0xff, 1_000 and 2.5, but 0..5 is a range
+            while i < chars.len()
+                && (is_ident(chars[i])
+                    || chars[i] == '.' && chars.get(i + 1).is_some_and(char::is_ascii_digit))
+            {
+                i += 1;
+            }
+            Some("number")
+        } else if is_ident(c) {
+            while i < chars.len() && is_ident(chars[i]) {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            if chars.get(i) == Some(&'!') && chars.get(i + 1) != Some(&'=') {
+                i += 1;
+                Some("macro")
+            } else if KEYWORDS.contains(&word.as_str()) {
+                Some("keyword")
+            } else if c.is_uppercase() {
+                Some("type")
+            } else {
+                None
+            }
+        } else {
+            i += 1;
+            None
+        };
+
+        let text = escape(&chars[start..i].iter().collect::<String>());
+        match class {
+            Some(class) => html.push_str(&format!("<span class=\"{class}\">{text}</span>")),
+            None => html.push_str(&text),
+        }
+    }
+    html
+}
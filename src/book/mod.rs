@@ -0,0 +1,377 @@
+/*
+    Book export
+*/
+// `localplayground book <dir>` turns the lessons into a small static site to read them
+// like a book, `--markdown` writes Markdown files instead of HTML.
+// The sources are read like this:
+//     /*              a section header, further lines in it are prose
+//         Title
+//     */
+//     // comment      prose, a line of its own when it's indented
+//     //      Title   a subsection, when the comment is indented that far
+//     everything else is code, comments inside functions stay part of it
+// What comes before the first header is the lesson registration and is left out.
+// The pages don't load anything, the style sheet is copied into every page.
+
+mod highlight;
+
+use std::fs;
+use std::path::Path;
+
+use crate::i18n;
+use crate::lessons::{Lesson, LESSONS};
+
+use highlight::escape;
+
+const CSS: &str = include_str!("book.css");
+
+enum Block {
+    // level 2 for the /* */ headers, 3 for the subsections
+    Heading {
+        level: usize,
+        title: String,
+        id: String,
+    },
+    // one paragraph, true if the line has to start on a line of its own
+    Prose(Vec<(bool, String)>),
+    Code(String),
+}
+
+struct Page {
+    lesson: &'static Lesson,
+    blocks: Vec<Block>,
+}
+
+impl Page {
+    fn headings(&self) -> impl Iterator<Item = (usize, &str, &str)> {
+        self.blocks.iter().filter_map(|block| match block {
+            Block::Heading { level, title, id } => Some((*level, title.as_str(), id.as_str())),
+            _ => None,
+        })
+    }
+}
+
+// anchors like GitHub makes them, so the Markdown links work there too
+fn slug(title: &str, taken: &mut Vec<String>) -> String {
+    let base: String = title
+        .trim()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' | '-' => Some('-'),
+            '_' => Some('_'),
+            c if c.is_alphanumeric() => Some(c.to_lowercase().next().unwrap_or(c)),
+            _ => None,
+        })
+        .collect();
+    let mut id = base.clone();
+    let mut n = 1;
+    while taken.contains(&id) {
+        id = format!("{base}-{n}");
+        n += 1;
+    }
+    taken.push(id.clone());
+    id
+}
+
+fn parse(source: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut code = String::new();
+    let mut prose = Vec::new();
+    let mut ids = Vec::new();
+
+    fn flush_code(blocks: &mut Vec<Block>, code: &mut String) {
+        let finished = code.trim_matches('\n');
+        if !finished.is_empty() {
+            blocks.push(Block::Code(finished.to_string()));
+        }
+        code.clear();
+    }
+    fn flush_prose(blocks: &mut Vec<Block>, prose: &mut Vec<(bool, String)>) {
+        if !prose.is_empty() {
+            blocks.push(Block::Prose(std::mem::take(prose)));
+        }
+    }
+
+    let mut lines = source.lines().skip_while(|line| !line.starts_with("/*"));
+    while let Some(line) = lines.next() {
+        if line.starts_with("/*") {
+            flush_code(&mut blocks, &mut code);
+            flush_prose(&mut blocks, &mut prose);
+            let mut comment = vec![line];
+            while !comment[comment.len() - 1].contains("*/") {
+                match lines.next() {
+                    Some(line) => comment.push(line),
+                    None => break,
+                }
+            }
+            let header = line.trim() == "/*";
+            let mut texts = comment
+                .iter()
+                .map(|line| line.replace("/*", "").replace("*/", "").trim().to_string())
+                .filter(|text| !text.is_empty());
+            if header {
+                if let Some(title) = texts.next() {
+                    let id = slug(&title, &mut ids);
+                    blocks.push(Block::Heading {
+                        level: 2,
+                        title,
+                        id,
+                    });
+                }
+            }
+            let texts: Vec<(bool, String)> = texts.map(|text| (true, text)).collect();
+            if !texts.is_empty() {
+                blocks.push(Block::Prose(texts));
+            }
+        } else if let Some(text) = line.strip_prefix("//") {
+            let text = text.strip_prefix(' ').unwrap_or(text);
+            let indent = text.len() - text.trim_start().len();
+            if text.trim().is_empty() {
+                flush_code(&mut blocks, &mut code);
+                flush_prose(&mut blocks, &mut prose);
+            } else if indent >= 4 {
+                flush_code(&mut blocks, &mut code);
+                flush_prose(&mut blocks, &mut prose);
+                let title = text.trim().to_string();
+                let id = slug(&title, &mut ids);
+                blocks.push(Block::Heading {
+                    level: 3,
+                    title,
+                    id,
+                });
+            } else {
+                flush_code(&mut blocks, &mut code);
+                prose.push((indent > 0, text.trim().to_string()));
+            }
+        } else if line.trim().is_empty() {
+            flush_prose(&mut blocks, &mut prose);
+            if !code.is_empty() {
+                code.push('\n');
+            }
+        } else {
+            flush_prose(&mut blocks, &mut prose);
+            code.push_str(line);
+            code.push('\n');
+        }
+    }
+    flush_code(&mut blocks, &mut code);
+    flush_prose(&mut blocks, &mut prose);
+    blocks
+}
+
+/* HTML */
+
+// `code` in the prose becomes <code>
+fn inline_html(text: &str) -> String {
+    text.split('`')
+        .enumerate()
+        .map(|(i, part)| match i % 2 {
+            0 => escape(part),
+            _ => format!("<code>{}</code>", escape(part)),
+        })
+        .collect()
+}
+
+fn html_page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"{}\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>{}</title>\n<style>\n{CSS}</style>\n</head>\n<body>\n{body}</body>\n</html>\n",
+        i18n::lang().code(),
+        escape(title)
+    )
+}
+
+fn html_nav(pages: &[Page], index: usize, class: &str) -> String {
+    let link = |page: Option<&Page>, key: &str| match page {
+        Some(page) => format!("<a href=\"{}.html\">{}</a>", page.lesson.name, label(key)),
+        None => "<span></span>".to_string(),
+    };
+    format!(
+        "<nav class=\"{class}\">{}<a href=\"index.html\">{}</a>{}</nav>\n",
+        link(
+            index.checked_sub(1).and_then(|i| pages.get(i)),
+            "book.previous"
+        ),
+        label("book.contents"),
+        link(pages.get(index + 1), "book.next")
+    )
+}
+
+fn html_lesson(pages: &[Page], index: usize) -> String {
+    let page = &pages[index];
+    let title = page.lesson.title();
+    let mut body = html_nav(pages, index, "top");
+    body.push_str(&format!("<h1>{}</h1>\n", escape(title)));
+    for paragraph in page.lesson.intro() {
+        body.push_str(&format!("<p><em>{}</em></p>\n", inline_html(paragraph)));
+    }
+    for block in &page.blocks {
+        match block {
+            Block::Heading { level, title, id } => body.push_str(&format!(
+                "<h{level} id=\"{id}\">{}</h{level}>\n",
+                escape(title)
+            )),
+            Block::Prose(lines) => {
+                let mut paragraph = String::new();
+                for (i, (own_line, text)) in lines.iter().enumerate() {
+                    if i > 0 {
+                        paragraph.push_str(if *own_line { "<br>\n" } else { "\n" });
+                    }
+                    paragraph.push_str(&inline_html(text));
+                }
+                body.push_str(&format!("<p>{paragraph}</p>\n"));
+            }
+            Block::Code(code) => body.push_str(&format!(
+                "<pre><code>{}</code></pre>\n",
+                highlight::html(code)
+            )),
+        }
+    }
+    body.push_str(&html_nav(pages, index, "bottom"));
+    html_page(title, &body)
+}
+
+fn html_index(pages: &[Page]) -> String {
+    let title = label("book.title");
+    let mut body = format!("<h1>{}</h1>\n<ol>\n", escape(title));
+    for page in pages {
+        let name = page.lesson.name;
+        body.push_str(&format!(
+            "<li><a href=\"{name}.html\">{}</a>\n<ul>\n",
+            escape(page.lesson.title())
+        ));
+        for (level, title, id) in page.headings() {
+            let class = if level > 2 { " class=\"sub\"" } else { "" };
+            body.push_str(&format!(
+                "<li{class}><a href=\"{name}.html#{id}\">{}</a></li>\n",
+                escape(title)
+            ));
+        }
+        body.push_str("</ul></li>\n");
+    }
+    body.push_str("</ol>\n");
+    html_page(title, &body)
+}
+
+/* Markdown */
+
+// escapes what Markdown would read as formatting, except inside `code`
+fn inline_markdown(text: &str) -> String {
+    text.split('`')
+        .enumerate()
+        .map(|(i, part)| match i % 2 {
+            0 => part
+                .chars()
+                .flat_map(|c| match c {
+                    '\\' | '*' | '_' | '<' | '>' | '[' | ']' | '#' => vec!['\\', c],
+                    c => vec![c],
+                })
+                .collect(),
+            _ => format!("`{part}`"),
+        })
+        .collect()
+}
+
+fn markdown_lesson(pages: &[Page], index: usize) -> String {
+    let page = &pages[index];
+    let mut text = format!("# {}\n\n", page.lesson.title());
+    for paragraph in page.lesson.intro() {
+        text.push_str(&format!("*{}*\n\n", inline_markdown(paragraph)));
+    }
+    for block in &page.blocks {
+        match block {
+            Block::Heading { level, title, .. } => {
+                text.push_str(&format!("{} {title}\n\n", "#".repeat(*level)))
+            }
+            Block::Prose(lines) => {
+                for (i, (own_line, line)) in lines.iter().enumerate() {
+                    if i > 0 {
+                        // two spaces at the end of a line are a line break
+                        text.push_str(if *own_line { "  \n" } else { "\n" });
+                    }
+                    text.push_str(&inline_markdown(line));
+                }
+                text.push_str("\n\n");
+            }
+            Block::Code(code) => text.push_str(&format!("```rust\n{code}\n```\n\n")),
+        }
+    }
+    let mut nav = Vec::new();
+    if let Some(previous) = index.checked_sub(1).and_then(|i| pages.get(i)) {
+        nav.push(format!(
+            "[{}]({}.md)",
+            label("book.previous"),
+            previous.lesson.name
+        ));
+    }
+    nav.push(format!("[{}](index.md)", label("book.contents")));
+    if let Some(next) = pages.get(index + 1) {
+        nav.push(format!("[{}]({}.md)", label("book.next"), next.lesson.name));
+    }
+    text.push_str(&format!("---\n\n{}\n", nav.join(" | ")));
+    text
+}
+
+fn markdown_index(pages: &[Page]) -> String {
+    let mut text = format!("# {}\n\n", label("book.title"));
+    for (number, page) in pages.iter().enumerate() {
+        let name = page.lesson.name;
+        text.push_str(&format!(
+            "{}. [{}]({name}.md)\n",
+            number + 1,
+            page.lesson.title()
+        ));
+        for (level, title, id) in page.headings() {
+            let indent = if level > 2 { "        " } else { "    " };
+            text.push_str(&format!("{indent}- [{title}]({name}.md#{id})\n"));
+        }
+    }
+    text
+}
+
+fn label(key: &str) -> &'static str {
+    i18n::text(key).unwrap_or("")
+}
+
+// book <dir> [--markdown]
+pub fn command(args: &[String]) -> Result<(), String> {
+    let markdown = args.iter().any(|arg| arg == "--markdown");
+    let dir = args
+        .iter()
+        .find(|arg| *arg != "--markdown")
+        .ok_or("usage: book <dir> [--markdown]")?;
+    let dir = Path::new(dir);
+    fs::create_dir_all(dir).map_err(|e| format!("cannot create {}: {e}", dir.display()))?;
+
+    let pages: Vec<Page> = LESSONS
+        .iter()
+        .map(|lesson| Page {
+            lesson,
+            blocks: parse(lesson.source),
+        })
+        .collect();
+    let extension = if markdown { "md" } else { "html" };
+    let mut files = vec![(
+        format!("index.{extension}"),
+        if markdown {
+            markdown_index(&pages)
+        } else {
+            html_index(&pages)
+        },
+    )];
+    for (index, page) in pages.iter().enumerate() {
+        let contents = if markdown {
+            markdown_lesson(&pages, index)
+        } else {
+            html_lesson(&pages, index)
+        };
+        files.push((format!("{}.{extension}", page.lesson.name), contents));
+    }
+
+    for (name, contents) in &files {
+        let path = dir.join(name);
+        fs::write(&path, contents).map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+    }
+    println!("wrote {} pages to {}", files.len(), dir.display());
+    Ok(())
+}
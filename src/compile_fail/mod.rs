@@ -0,0 +1,380 @@
+/*
+    Compile-fail examples
+*/
+// Some examples in the lessons only exist to show what the compiler rejects.
+// They are kept here instead of in the lessons, so the lessons themselves compile.
+// Each of them is an isolated snippet together with the error code rustc has to report.
+// `localplayground compile-fail` compiles them with the local rustc and checks
+// that they still fail, and fail for exactly that reason.
+
+use crate::lessons;
+use crate::source;
+use crate::toolchain::{self, Scratch};
+
+pub struct Snippet {
+    pub name: &'static str,
+    pub lesson: &'static str,
+    pub function: &'static str,
+    pub expected: &'static str,
+    pub code: &'static str,
+    // a module of the lesson the snippet builds on, copied below the snippet so the
+    // snippet keeps its line numbers and the lesson stays the only copy
+    pub module: Option<&'static str>,
+}
+
+pub static SNIPPETS: &[Snippet] = &[
+    Snippet {
+        name: "str_by_value",
+        lesson: "data_types",
+        function: "data_types",
+        expected: "E0277", // the size of str is not known at compile time
+        code: include_str!("snippets/str_by_value.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "not_possible",
+        lesson: "ownership",
+        function: "not_possible",
+        expected: "E0382", // borrow of moved value
+        code: include_str!("snippets/not_possible.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "moved_after_borrow",
+        lesson: "ownership",
+        function: "not_possible",
+        expected: "E0382",
+        code: include_str!("snippets/moved_after_borrow.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "copy_fails",
+        lesson: "ownership",
+        function: "copy_fails",
+        expected: "E0382",
+        code: include_str!("snippets/copy_fails.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "double_mut_borrowing",
+        lesson: "ownership",
+        function: "borrows_mutable",
+        expected: "E0499", // second mutable borrow while the first is alive
+        code: include_str!("snippets/double_mut_borrowing.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "overlapping_mut_borrowing",
+        lesson: "ownership",
+        function: "overlapping_mut_borrowing",
+        expected: "E0502", // mutable borrow while immutably borrowed
+        code: include_str!("snippets/overlapping_mut_borrowing.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "immutable_reassign",
+        lesson: "control_flow",
+        function: "if_else_decisions",
+        expected: "E0384", // assigning twice to an immutable variable
+        code: include_str!("snippets/immutable_reassign.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "option_plus_value",
+        lesson: "structs_enums",
+        function: "options",
+        expected: "E0369", // binary operation not supported for the type
+        code: include_str!("snippets/option_plus_value.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "non_exhaustive_store",
+        lesson: "structs_enums",
+        function: "this_enum",
+        expected: "E0004", // non-exhaustive patterns
+        code: include_str!("snippets/non_exhaustive_store.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "question_mark_unwrapped",
+        lesson: "structs_enums",
+        function: "really_short_option",
+        expected: "E0308",
+        code: include_str!("snippets/question_mark_unwrapped.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "refutability",
+        lesson: "patterns",
+        function: "refutability",
+        expected: "E0005", // refutable pattern in local binding
+        code: include_str!("snippets/refutability.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "refutability_tuple",
+        lesson: "patterns",
+        function: "refutability",
+        expected: "E0308", // mismatched types
+        code: include_str!("snippets/refutability_tuple.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "temporary_dropped",
+        lesson: "patterns",
+        function: "decomposing",
+        expected: "E0716", // temporary value dropped while borrowed
+        code: include_str!("snippets/temporary_dropped.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "into_iter_moved",
+        lesson: "iterators",
+        function: "iterators",
+        expected: "E0382",
+        code: include_str!("snippets/into_iter_moved.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "highest_without_bound",
+        lesson: "traits",
+        function: "generic_highest",
+        expected: "E0369",
+        code: include_str!("snippets/highest_without_bound.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "copy_without_clone",
+        lesson: "traits",
+        function: "copy_clone_display",
+        expected: "E0277", // Clone is not implemented
+        code: include_str!("snippets/copy_without_clone.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "blanket_to_string",
+        lesson: "traits",
+        function: "conditional_implementation",
+        expected: "E0210", // orphan rule
+        code: include_str!("snippets/blanket_to_string.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "longest_dangling",
+        lesson: "lifetimes",
+        function: "longest",
+        expected: "E0515", // returns a reference to a local value
+        code: include_str!("snippets/longest_dangling.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "static_ref_to_local",
+        lesson: "lifetimes",
+        function: "new_counter",
+        expected: "E0515",
+        code: include_str!("snippets/static_ref_to_local.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "recursive_without_box",
+        lesson: "smart_pointers",
+        function: "recursive_list",
+        expected: "E0072", // recursive type has infinite size
+        code: include_str!("snippets/recursive_without_box.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "thread_borrows_local",
+        lesson: "concurrency",
+        function: "spawning",
+        expected: "E0373", // closure may outlive the current function
+        code: include_str!("snippets/thread_borrows_local.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "rc_not_send",
+        lesson: "concurrency",
+        function: "send_sync",
+        expected: "E0277", // Rc cannot be sent between threads safely
+        code: include_str!("snippets/rc_not_send.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "arc_without_mutex",
+        lesson: "concurrency",
+        function: "arc_mutex",
+        expected: "E0594", // cannot assign to data in an Arc
+        code: include_str!("snippets/arc_without_mutex.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "scoped_data_race",
+        lesson: "concurrency",
+        function: "scoped_threads",
+        expected: "E0499",
+        code: include_str!("snippets/scoped_data_race.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "pin_async_block",
+        lesson: "async_await",
+        function: "pinning",
+        expected: "E0277", // the async block is not Unpin
+        code: include_str!("snippets/pin_async_block.rs"),
+        module: None,
+    },
+    Snippet {
+        name: "typestate_skip_review",
+        lesson: "oop",
+        function: "typestate",
+        expected: "E0599", // no method with that name on the type
+        code: include_str!("snippets/typestate_skip_review.rs"),
+        module: Some("typed_post"),
+    },
+    Snippet {
+        name: "typestate_draft_content",
+        lesson: "oop",
+        function: "typestate",
+        expected: "E0599",
+        code: include_str!("snippets/typestate_draft_content.rs"),
+        module: Some("typed_post"),
+    },
+    Snippet {
+        name: "typestate_edit_pending",
+        lesson: "oop",
+        function: "same_rules",
+        expected: "E0599",
+        code: include_str!("snippets/typestate_edit_pending.rs"),
+        module: Some("typed_post"),
+    },
+    Snippet {
+        name: "typestate_reject_published",
+        lesson: "oop",
+        function: "same_rules",
+        expected: "E0599",
+        code: include_str!("snippets/typestate_reject_published.rs"),
+        module: Some("typed_post"),
+    },
+];
+
+pub enum Verdict {
+    // failed with the expected error code and nothing else
+    Fails,
+    Compiles,
+    WrongErrors(Vec<String>),
+}
+
+// the snippet with the lesson module it builds on
+pub fn program(snippet: &Snippet) -> Result<String, String> {
+    let Some(name) = snippet.module else {
+        return Ok(snippet.code.to_string());
+    };
+    let lesson = lessons::find(snippet.lesson)
+        .ok_or_else(|| format!("snippet {} names an unknown lesson", snippet.name))?;
+    let module = source::module(lesson.source, name)
+        .ok_or_else(|| format!("{} has no module `{name}`", snippet.lesson))?;
+    Ok(format!(
+        "{}
+{}
+",
+        snippet.code,
+        module.join("\n")
+    ))
+}
+
+// compiles the snippet and returns the verdict together with rustc's diagnostics
+pub fn check(snippet: &Snippet) -> Result<(Verdict, String), String> {
+    let scratch = Scratch::new("compile-fail")?;
+    let file = scratch.write(&format!("{}.rs", snippet.name), &program(snippet)?)?;
+    let output = toolchain::rustc()
+        .args([
+            "--crate-type",
+            "lib",
+            "--emit=metadata",
+            "--error-format=short",
+        ])
+        .args(["-A", "warnings", "--out-dir"])
+        .arg(scratch.path())
+        .arg(&file)
+        .output()
+        .map_err(|e| format!("cannot run rustc: {e}"))?;
+    let diagnostics = String::from_utf8_lossy(&output.stderr).into_owned();
+    if output.status.success() {
+        return Ok((Verdict::Compiles, diagnostics));
+    }
+    let codes = error_codes(&diagnostics);
+    let verdict = if !codes.is_empty() && codes.iter().all(|code| code == snippet.expected) {
+        Verdict::Fails
+    } else {
+        Verdict::WrongErrors(codes)
+    };
+    Ok((verdict, diagnostics))
+}
+
+// picks the codes out of lines like `a.rs:3:27: error[E0369]: cannot add ...`
+pub fn error_codes(diagnostics: &str) -> Vec<String> {
+    let mut codes: Vec<String> = diagnostics
+        .lines()
+        .filter_map(|line| {
+            let start = line.find("error[")? + "error[".len();
+            let end = line[start..].find(']')? + start;
+            Some(line[start..end].to_string())
+        })
+        .collect();
+    codes.dedup();
+    codes
+}
+
+// the line numbers of the errors, in the order rustc reports them
+pub fn error_lines(diagnostics: &str) -> Vec<usize> {
+    diagnostics
+        .lines()
+        .filter(|line| line.contains(": error"))
+        .filter_map(|line| line.split(':').nth(1)?.parse().ok())
+        .collect()
+}
+
+pub fn find(name: &str) -> Option<&'static Snippet> {
+    SNIPPETS.iter().find(|snippet| snippet.name == name)
+}
+
+// compile-fail [snippet] [--show]
+pub fn command(args: &[String]) -> Result<(), String> {
+    let show = args.iter().any(|arg| arg == "--show");
+    let selected: Vec<&Snippet> = match args.iter().find(|arg| *arg != "--show") {
+        Some(name) => vec![find(name).ok_or_else(|| format!("unknown snippet `{name}`"))?],
+        None => SNIPPETS.iter().collect(),
+    };
+
+    let mut broken = 0;
+    for snippet in selected {
+        let (verdict, diagnostics) = check(snippet)?;
+        let place = format!("{}::{}", snippet.lesson, snippet.function);
+        match verdict {
+            Verdict::Fails => println!("ok    {:<28} {} ({place})", snippet.name, snippet.expected),
+            Verdict::Compiles => {
+                broken += 1;
+                println!(
+                    "FAIL  {:<28} compiles, expected {}",
+                    snippet.name, snippet.expected
+                );
+            }
+            Verdict::WrongErrors(codes) => {
+                broken += 1;
+                println!(
+                    "FAIL  {:<28} expected {}, rustc reported {:?}",
+                    snippet.name, snippet.expected, codes
+                );
+            }
+        }
+        if show {
+            println!("{}\n{diagnostics}", program(snippet)?);
+        }
+    }
+    match broken {
+        0 => Ok(()),
+        n => Err(format!(
+            "{n} snippet(s) no longer fail the way the lessons claim"
+        )),
+    }
+}
@@ -0,0 +1,10 @@
+// Concurrency: Arc only hands out shared references, mutating needs a Mutex
+use std::sync::Arc;
+use std::thread;
+
+fn arc_mutex() {
+    let counter = Arc::new(0);
+    let for_thread = Arc::clone(&counter);
+    thread::spawn(move || *for_thread += 1).join().unwrap();
+    println!("{counter}");
+}
@@ -0,0 +1,6 @@
+// Generics and Traits: impl ToString for any type that implements Display trait
+// std already does exactly this, and we may not do it ourselves: neither ToString
+// nor T belong to our crate (orphan rule)
+use std::fmt::Display;
+
+impl<T: Display> ToString for T {}
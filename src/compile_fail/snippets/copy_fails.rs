@@ -0,0 +1,12 @@
+// Copy and Clone Trait: structs are moved because they don't have the Copy Trait
+struct RandomStruct {}
+
+fn random_func(x: RandomStruct) -> RandomStruct {
+    x
+}
+
+fn copy_fails() {
+    let a = RandomStruct {};
+    random_func(a); // this is fine, but a is not the owner anymore
+    random_func(a); // this is not fine, because a has no ownership rights
+}
@@ -0,0 +1,3 @@
+// Generics and Traits: Types that have Copy, must implement Clone
+struct Foo {}
+impl Copy for Foo {}
@@ -0,0 +1,13 @@
+// Ownership und Borrowing: mutable borrowing allows only one borrow at a time
+fn borrows_mutable(text: &mut String) {
+    println!("{}", text);
+    *text = format!("{}{}", text, " 13")
+}
+
+fn double_mut_borrowing() {
+    let mut text = String::from("jeremias");
+    let first = &mut text;
+    let second = &mut text; // the second mutable borrow, while first is still used below
+    borrows_mutable(first);
+    borrows_mutable(second);
+}
@@ -0,0 +1,12 @@
+// Generics and Traits: without a bound the compiler doesn't know T can be compared
+fn generic_highest<T>(list: &[T]) -> &T {
+    let mut highest = &list[0];
+
+    for item in list {
+        if highest < item {
+            // compare the Types (they need to implement the comparison)
+            highest = item;
+        }
+    }
+    highest
+}
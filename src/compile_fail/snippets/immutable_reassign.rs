@@ -0,0 +1,11 @@
+// Kontrollstrukturen und Schleifen: mutability needs to be declared
+fn if_else_decisons() {
+    let n = 5;
+    if 1 == n {
+        n += 2;
+    } else if n < 6 {
+        n -= 1;
+    } else {
+        n = n + 3;
+    }
+}
@@ -0,0 +1,9 @@
+// Iterators: into_iter consumes the collection
+fn iterators() {
+    let names = vec!["Bob", "Frank", "Ferris"];
+    for name in names.into_iter() {
+        // this iterator consumes the collection. It will not be available after this iteration
+        println!("{}", name);
+    }
+    println!("{}", names[0]); // will FAIL! because into_iter takes ownership
+}
@@ -0,0 +1,5 @@
+// Lifetime: result is dropped after longest() and is unassociated with the lifetime 'a
+fn longest<'a>(x: &str, y: &str) -> &'a str {
+    let result = String::from("really long string");
+    result.as_str() // this will fail!
+}
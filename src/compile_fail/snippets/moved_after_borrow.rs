@@ -0,0 +1,13 @@
+// Ownership und Borrowing: the mutable borrow is over, but the move is not
+fn borrows_mutable(text: &mut String) {
+    println!("{}", text);
+    *text = format!("{}{}", text, " 13")
+}
+
+fn not_possible() {
+    let mut text = String::from("jeremias");
+    borrows_mutable(&mut text); // this works
+    let a = text; // this still works, because the function is finished with borrowing
+
+    println!("{} {}", a, text); // this now doesn't work because text was moved into a!
+}
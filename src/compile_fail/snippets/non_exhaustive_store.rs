@@ -0,0 +1,15 @@
+// Enums: a match has to handle every variant
+enum Store {
+    Online,
+    Local,
+    Brand,
+    PopUp,
+}
+
+fn this_enum() {
+    let store: Store = Store::Online;
+    match store {
+        Store::Online => println!("Beep Boop"),
+        Store::Local => println!("Cash please!"),
+    }
+}
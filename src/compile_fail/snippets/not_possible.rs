@@ -0,0 +1,10 @@
+// Ownership und Borrowing: use after move
+fn takes_ownership(text: String) {
+    println!("{}", text);
+}
+
+fn not_possible() {
+    let text: String = String::from("jeremias");
+    takes_ownership(text);
+    println!("{}", text); // this fails as text is invalidated
+}
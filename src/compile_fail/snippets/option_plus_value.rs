@@ -0,0 +1,5 @@
+// Option<T>: We cannot add Some(value) with value
+fn options() {
+    let some_number = Some(5);
+    let sum = some_number + 4; // this fails
+}
@@ -0,0 +1,29 @@
+// Ownership und Borrowing: a shared borrow is alive until its last use
+struct Counter {
+    name: String,
+    counter: u32,
+}
+
+fn new_counter(name: String) -> &'static mut Counter {
+    Box::leak(Box::new(Counter { name, counter: 0 }))
+}
+
+fn increment(counter: &mut Counter) {
+    counter.counter += 1;
+}
+
+fn as_string(counter: &Counter) -> String {
+    format!("{}: {}", counter.name, counter.counter)
+}
+
+fn overlapping_mut_borrowing() -> String {
+    let cnt = new_counter("my counter".to_string());
+    increment(cnt);
+    increment(cnt);
+    let cnt_ref1 = &(*cnt);
+    let cnt_ref2 = &(*cnt);
+    as_string(cnt_ref1);
+    increment(cnt); // this fails, because cnt_ref2 is still borrowing cnt
+    as_string(cnt_ref2) // this is because cnt_ref2 is borrowed until it's last use HERE
+    //if the last line was removed, it would compile, or increment was under the alst use of cnt_ref2
+}
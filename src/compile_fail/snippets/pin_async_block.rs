@@ -0,0 +1,8 @@
+// Async/await: async blocks may point into themselves, so they are not Unpin
+use std::pin::Pin;
+
+fn pinning() {
+    let mut future = async { 42 };
+    let pinned = Pin::new(&mut future); // needs Unpin, Box::pin or pin! work
+    drop(pinned);
+}
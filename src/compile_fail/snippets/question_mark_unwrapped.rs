@@ -0,0 +1,5 @@
+// Option<T>: after ? we hold the plain value, it has to be wrapped again
+fn really_short_option(option: Option<i32>) -> Option<i32> {
+    let a = option?; // this returns None instantly, if the Opion is not of kind Some(i)
+    a + 1 // an i32 is not an Option<i32>
+}
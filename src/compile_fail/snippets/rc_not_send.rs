@@ -0,0 +1,14 @@
+// Concurrency: Rc and RefCell are not thread safe, so they can't be moved into a thread
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::thread;
+
+fn rc_not_send() {
+    let list = Rc::new(RefCell::new(vec![5, 10]));
+    let for_thread = Rc::clone(&list);
+    // two threads could change the reference count at the same time
+    thread::spawn(move || for_thread.borrow_mut().push(3))
+        .join()
+        .unwrap();
+    println!("{:?}", list.borrow());
+}
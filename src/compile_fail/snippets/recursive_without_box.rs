@@ -0,0 +1,5 @@
+// Smart Pointers: a recursive type without indirection has no known size
+enum List {
+    Cons(i32, List), // every Element contains a value and the next part of the List
+    Nil,             // the end of the list
+}
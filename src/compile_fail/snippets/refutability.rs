@@ -0,0 +1,6 @@
+// Definition Refutability: let needs a pattern that always matches
+fn refutability() {
+    let some_value: Option<i32> = None;
+    let Some(x) = some_value; // this gives an error, as the Patterns don't match
+    println!("{}", x);
+}
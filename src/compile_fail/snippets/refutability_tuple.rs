@@ -0,0 +1,5 @@
+// Definition Refutability: the tuple has three elements, the pattern only two
+fn refutability() {
+    let (x, y) = (1, 2, 3); // also doesn't match
+    println!("{} {}", x, y);
+}
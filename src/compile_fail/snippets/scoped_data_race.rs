@@ -0,0 +1,12 @@
+// Concurrency: two threads changing the same variable is a data race, the borrow checker
+// rejects the second &mut like it would without threads
+use std::thread;
+
+fn scoped_threads() {
+    let mut counter = 0;
+    thread::scope(|s| {
+        s.spawn(|| counter += 1);
+        s.spawn(|| counter += 1);
+    });
+    println!("{counter}");
+}
@@ -0,0 +1,9 @@
+// Lifetime: a reference to a value created in the function can't be 'static
+struct Counter {
+    name: String,
+    counter: u32,
+}
+
+fn new_counter(name: String) -> &'static mut Counter {
+    &mut Counter { name, counter: 0 } // the Counter dies at the end of the function
+}
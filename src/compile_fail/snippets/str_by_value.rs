@@ -0,0 +1,4 @@
+// Datentypen: str has no size known at compile time, it only exists behind a reference
+fn data_types() {
+    let string: str = *"oh yeah";
+}
@@ -0,0 +1,18 @@
+// Decompose Types: the temporary that stores the result of `temp()` only lives until the
+// end of the let statement in these cases.
+struct Temp(i32);
+
+impl Temp {
+    fn use_temp(&self) -> &i32 {
+        &self.0
+    }
+}
+
+fn temp() -> Temp {
+    Temp(5)
+}
+
+fn decomposing() {
+    let x = temp().use_temp(); // ERROR
+    println!("{}", x);
+}
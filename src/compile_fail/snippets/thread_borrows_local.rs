@@ -0,0 +1,8 @@
+// Concurrency: a spawned thread may outlive the function, it can't borrow its locals
+use std::thread;
+
+fn spawning() {
+    let name = String::from("worker");
+    let handle = thread::spawn(|| format!("{name} done")); // borrows name, move is missing
+    println!("{}", handle.join().unwrap());
+}
@@ -0,0 +1,7 @@
+// OOP: a draft has no content method, so nobody can show it before it is published
+// (typed_post is copied from the oop lesson below this)
+fn typestate() {
+    let mut post = typed_post::Post::new();
+    post.add_text("I ate a salad for lunch today");
+    println!("{}", post.content()); // DraftPost has no method `content`
+}
@@ -0,0 +1,8 @@
+// OOP: only drafts can be edited, request_review consumed the draft
+// (typed_post is copied from the oop lesson below this)
+fn same_rules() {
+    let mut draft = typed_post::Post::new();
+    draft.add_text("salad");
+    let mut pending = draft.request_review();
+    pending.add_text(" and cake"); // PendingReviewPost has no method `add_text`
+}
@@ -0,0 +1,8 @@
+// OOP: a published post can't go back to draft
+// (typed_post is copied from the oop lesson below this)
+fn same_rules() {
+    let mut draft = typed_post::Post::new();
+    draft.add_text("salad");
+    let published = draft.request_review().approve();
+    published.reject(); // Post has no method `reject`
+}
@@ -0,0 +1,8 @@
+// OOP: with typestate a step the workflow doesn't allow is a method that doesn't exist
+// (typed_post is copied from the oop lesson below this)
+fn typestate() {
+    let mut post = typed_post::Post::new();
+    post.add_text("I ate a salad for lunch today");
+    let post = post.approve(); // a draft can't be approved, it has to be reviewed first
+    println!("{}", post.content());
+}
@@ -0,0 +1,201 @@
+/*
+    Std API coverage
+*/
+// Which parts of std do the lessons actually show? `localplayground coverage` collects
+// every path (`HashMap::new`) and method call (`.checked_mul(`) of the lessons compiled
+// into the playground and compares them with the curated list in apis.txt.
+// A call only counts for a module when the function also mentions the module's type,
+// otherwise `.take()` on an iterator would count for Option. Names like `iter` only
+// count in the statement of the call, `v.iter().map(..)`. In a method the fields of
+// self it uses count as mentioned too: `self.list.pop()` pops a Vec if list is a Vec.
+// It's a guess from reading the source, not what the compiler knows.
+// The report lists the taught APIs with where they are used and the untaught ones.
+
+use crate::lessons::LESSONS;
+use crate::source;
+
+const APIS: &str = include_str!("apis.txt");
+
+// search uses the same list to guess the type of a method call
+pub struct Module {
+    pub name: String,
+    pub seen_with: Vec<String>,
+    pub called_on: Vec<String>,
+    pub apis: Vec<String>,
+}
+
+impl Module {
+    // names: everything the function mentions, statement: the lines of the call's statement
+    pub fn mentioned(&self, names: &[&str], statement: &[&str]) -> bool {
+        let statement: Vec<&str> = statement
+            .iter()
+            .flat_map(|line| source::identifiers(line))
+            .map(|(_, name)| name)
+            .collect();
+        self.seen_with
+            .iter()
+            .any(|name| names.contains(&name.as_str()))
+            || self
+                .called_on
+                .iter()
+                .any(|name| statement.contains(&name.as_str()))
+    }
+}
+
+// the lines of code up to `index` that belong to its statement, rustfmt puts every call
+// of a long chain on its own line starting with a dot
+pub fn statement<'a, 'b>(code: &'b [&'a str], index: usize) -> &'b [&'a str] {
+    let mut start = index;
+    while start > 0 && code[start].trim_start().starts_with('.') {
+        start -= 1;
+    }
+    &code[start..=index]
+}
+
+pub fn modules() -> Result<Vec<Module>, String> {
+    let mut modules: Vec<Module> = Vec::new();
+    for (index, line) in APIS.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(name) = line
+            .strip_prefix('[')
+            .and_then(|line| line.strip_suffix(']'))
+        {
+            modules.push(Module {
+                name: name.to_string(),
+                seen_with: Vec::new(),
+                called_on: Vec::new(),
+                apis: Vec::new(),
+            });
+            continue;
+        }
+        let error = || format!("apis.txt:{}: cannot read `{line}`", index + 1);
+        let module = modules.last_mut().ok_or_else(error)?;
+        let (key, value) = line.split_once('=').ok_or_else(error)?;
+        let names = value.split_whitespace().map(str::to_string).collect();
+        match key.trim() {
+            "seen with" => module.seen_with = names,
+            "called on" => module.called_on = names,
+            "apis" => module.apis = names,
+            _ => return Err(error()),
+        }
+    }
+    Ok(modules)
+}
+
+// `Module::api` -> the lesson functions using it
+struct Usage {
+    api: String,
+    places: Vec<String>,
+}
+
+fn usages(modules: &[Module]) -> Vec<Usage> {
+    let mut usages: Vec<Usage> = Vec::new();
+    let mut used = |api: String, place: String| match usages.iter_mut().find(|u| u.api == api) {
+        Some(usage) if !usage.places.contains(&place) => usage.places.push(place),
+        Some(_) => (),
+        None => usages.push(Usage {
+            api,
+            places: vec![place],
+        }),
+    };
+
+    for lesson in LESSONS {
+        for function in source::functions(lesson.source) {
+            let place = format!("{}::{}", lesson.name, function.name);
+            let code: Vec<&str> = function
+                .lines
+                .iter()
+                .map(|(_, line)| source::code(line))
+                .collect();
+            let fields = function
+                .lines
+                .first()
+                .and_then(|&(number, _)| source::impl_type(lesson.source, number))
+                .map(|name| source::struct_lines(lesson.source, name))
+                .unwrap_or_default();
+            // only the fields the method uses, `list: Vec<i32>` when it says self.list
+            let fields: Vec<&str> = fields
+                .into_iter()
+                .filter(|line| {
+                    let field = line.trim_start().trim_start_matches("pub ");
+                    field.split_once(':').is_some_and(|(name, _)| {
+                        code.iter()
+                            .any(|line| line.contains(&format!("self.{name}")))
+                    })
+                })
+                .collect();
+            let names: Vec<&str> = code
+                .iter()
+                .chain(fields.iter())
+                .flat_map(|line| source::identifiers(source::code(line)))
+                .map(|(_, name)| name)
+                .collect();
+
+            for (index, line) in code.iter().enumerate() {
+                let (paths, calls) = source::paths_and_calls(line);
+                for path in paths {
+                    used(path, place.clone());
+                }
+                for module in modules {
+                    if module.mentioned(&names, statement(&code, index)) {
+                        for call in &calls {
+                            used(format!("{}::{call}", module.name), place.clone());
+                        }
+                    }
+                }
+            }
+        }
+    }
+    usages
+}
+
+// coverage [module] [--where]
+pub fn command(args: &[String]) -> Result<(), String> {
+    let show_places = args.iter().any(|arg| arg == "--where");
+    let mut modules = modules()?;
+    if let Some(name) = args.iter().find(|arg| *arg != "--where") {
+        modules.retain(|module| module.name.eq_ignore_ascii_case(name));
+        if modules.is_empty() {
+            return Err(format!("`{name}` is not in the curated list"));
+        }
+    }
+    let usages = usages(&modules);
+
+    let (mut taught_total, mut total) = (0, 0);
+    for module in &modules {
+        let (taught, untaught): (Vec<&String>, Vec<&String>) =
+            module.apis.iter().partition(|api| {
+                let path = format!("{}::{api}", module.name);
+                usages.iter().any(|usage| usage.api == path)
+            });
+        taught_total += taught.len();
+        total += module.apis.len();
+        println!(
+            "{:<10} {:>2}/{:<2} taught",
+            module.name,
+            taught.len(),
+            module.apis.len()
+        );
+        if show_places {
+            for api in &taught {
+                let path = format!("{}::{api}", module.name);
+                if let Some(usage) = usages.iter().find(|usage| usage.api == path) {
+                    println!("    {api:<18} {}", usage.places.join(", "));
+                }
+            }
+        } else if !taught.is_empty() {
+            let names: Vec<&str> = taught.iter().map(|api| api.as_str()).collect();
+            println!("    taught:   {}", names.join(" "));
+        }
+        if !untaught.is_empty() {
+            let names: Vec<&str> = untaught.iter().map(|api| api.as_str()).collect();
+            println!("    untaught: {}", names.join(" "));
+        }
+    }
+    println!("{taught_total}/{total} core APIs are taught somewhere");
+    println!("(guessed from the source: a call counts for a type if its function or a field of self it uses names the type, or its own statement starts from something like `.iter()`)");
+    Ok(())
+}
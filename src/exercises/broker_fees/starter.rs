@@ -0,0 +1,20 @@
+// Project: Paper trading portfolio
+// Every order costs a fee, and every broker charges differently.
+// Money is counted in cents, Money(495) is 4.95.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Money(pub i64);
+
+pub enum Broker {
+    // 1.00 per order, no matter how big it is
+    Neobroker { name: String },
+    // the i32 is the commission in basis points (hundredths of a percent) of the
+    // order value, rounded down, but never less than 4.95
+    BankDepot(String, i32),
+}
+
+impl Broker {
+    pub fn fee(&self, value: Money) -> Money {
+        todo!("the fee of an order worth {value:?}")
+    }
+}
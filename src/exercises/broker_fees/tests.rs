@@ -0,0 +1,34 @@
+fn neobroker() -> Broker {
+    Broker::Neobroker {
+        name: String::from("Trade Republic"),
+    }
+}
+
+fn bank(basis_points: i32) -> Broker {
+    Broker::BankDepot(String::from("Deutsche Bank"), basis_points)
+}
+
+#[test]
+fn neobroker_is_flat() {
+    assert_eq!(neobroker().fee(Money(1_000)), Money(100));
+    assert_eq!(neobroker().fee(Money(10_000_000)), Money(100));
+}
+
+#[test]
+fn bank_takes_its_share() {
+    // 0.42% of 5000.00
+    assert_eq!(bank(42).fee(Money(500_000)), Money(2_100));
+    assert_eq!(bank(100).fee(Money(1_000_000)), Money(10_000));
+}
+
+#[test]
+fn bank_rounds_down() {
+    // 0.42% of 1234.56 is 5.185152
+    assert_eq!(bank(42).fee(Money(123_456)), Money(518));
+}
+
+#[test]
+fn bank_minimum() {
+    assert_eq!(bank(42).fee(Money(1_000)), Money(495));
+    assert_eq!(bank(0).fee(Money(500_000)), Money(495));
+}
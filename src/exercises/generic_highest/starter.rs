@@ -0,0 +1,14 @@
+// Generics and Traits
+// generic_highest should return the highest element of any list,
+// but the compiler doesn't know yet that T can be compared.
+
+pub fn generic_highest<T>(list: &[T]) -> &T {
+    let mut highest = &list[0];
+
+    for item in list {
+        if highest < item {
+            highest = item;
+        }
+    }
+    highest
+}
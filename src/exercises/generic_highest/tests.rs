@@ -0,0 +1,25 @@
+#[test]
+fn highest_number() {
+    assert_eq!(*generic_highest(&[3, 42, 7]), 42);
+}
+
+#[test]
+fn highest_char() {
+    assert_eq!(*generic_highest(&['r', 'u', 's', 't']), 'u');
+}
+
+#[test]
+fn highest_float() {
+    assert_eq!(*generic_highest(&[1.5, -2.0, 2.5]), 2.5);
+}
+
+#[test]
+fn highest_str() {
+    assert_eq!(*generic_highest(&["Bob", "Frank", "Ferris"]), "Frank");
+}
+
+#[test]
+fn first_of_equals() {
+    let list = [(1, 'a'), (1, 'a')];
+    assert!(std::ptr::eq(generic_highest(&list), &list[0]));
+}
@@ -0,0 +1,11 @@
+// Lifetime
+// longest returns the longer of both strings, the first one if they are equally long.
+// The compiler can't tell which borrow the result belongs to, help it.
+
+pub fn longest(x: &str, y: &str) -> &str {
+    if x.len() > y.len() {
+        x
+    } else {
+        y
+    }
+}
@@ -0,0 +1,25 @@
+#[test]
+fn first_is_longer() {
+    assert_eq!(longest("jeremias", "ferris"), "jeremias");
+}
+
+#[test]
+fn second_is_longer() {
+    assert_eq!(longest("bob", "ferris"), "ferris");
+}
+
+#[test]
+fn equally_long_takes_first() {
+    assert_eq!(longest("abc", "xyz"), "abc");
+}
+
+#[test]
+fn outlives_the_shorter_one() {
+    let long = String::from("long string is long");
+    let result;
+    {
+        let short = String::from("xyz");
+        result = longest(long.as_str(), short.as_str()).to_string();
+    }
+    assert_eq!(result, "long string is long");
+}
@@ -0,0 +1,294 @@
+/*
+    Exercises
+*/
+// Every exercise ships a starter file for the learner and tests the learner never sees.
+// `localplayground start <exercise>` writes the starter to exercises/<name>.rs in the
+// current directory, `localplayground check <exercise>` compiles that file together with
+// the hidden tests and runs them.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::explain;
+use crate::i18n;
+use crate::progress::{self, Kind};
+use crate::sandbox::{self, Limits};
+use crate::toolchain::{self, Scratch};
+
+pub struct Exercise {
+    pub name: &'static str,
+    pub lesson: &'static str,
+    pub starter: &'static str,
+    pub tests: &'static str,
+}
+
+pub static EXERCISES: &[Exercise] = &[
+    Exercise {
+        name: "overlapping_borrows",
+        lesson: "ownership",
+        starter: include_str!("overlapping_borrows/starter.rs"),
+        tests: include_str!("overlapping_borrows/tests.rs"),
+    },
+    Exercise {
+        name: "really_short_option",
+        lesson: "structs_enums",
+        starter: include_str!("really_short_option/starter.rs"),
+        tests: include_str!("really_short_option/tests.rs"),
+    },
+    Exercise {
+        name: "generic_highest",
+        lesson: "traits",
+        starter: include_str!("generic_highest/starter.rs"),
+        tests: include_str!("generic_highest/tests.rs"),
+    },
+    Exercise {
+        name: "longest",
+        lesson: "lifetimes",
+        starter: include_str!("longest/starter.rs"),
+        tests: include_str!("longest/tests.rs"),
+    },
+    Exercise {
+        name: "broker_fees",
+        lesson: "portfolio",
+        starter: include_str!("broker_fees/starter.rs"),
+        tests: include_str!("broker_fees/tests.rs"),
+    },
+];
+
+pub fn find(name: &str) -> Result<&'static Exercise, String> {
+    EXERCISES
+        .iter()
+        .find(|exercise| exercise.name == name)
+        .ok_or_else(|| format!("unknown exercise `{name}`, see `localplayground exercises`"))
+}
+
+impl Exercise {
+    pub fn prompt(&self) -> &'static str {
+        i18n::text(&format!("exercise.{}", self.name)).unwrap_or("")
+    }
+
+    // where the learner works on it, relative to the current directory
+    pub fn solution_path(&self) -> PathBuf {
+        Path::new("exercises").join(format!("{}.rs", self.name))
+    }
+}
+
+pub struct TestRun {
+    pub passed: Vec<String>,
+    // name of the test and what it printed when it failed
+    pub failed: Vec<(String, String)>,
+}
+
+pub enum Outcome {
+    // the solution doesn't compile, with rustc's JSON diagnostics
+    CompileError(String),
+    // the tests never finished, like with an endless loop
+    Stopped(sandbox::Outcome),
+    Tested(TestRun),
+}
+
+// the tests are appended as a module, so they see the solution like a unit test would
+const HIDDEN_TESTS: &str = "\n\n#[cfg(test)]\nmod hidden_tests {\n    use super::*;\n\n";
+
+// the line of the compiled file where the hidden tests start
+fn tests_start(solution: &str) -> usize {
+    format!("{solution}{HIDDEN_TESTS}").matches('\n').count() + 1
+}
+
+// rustc and the tests name `<exercise>.rs:17:5`, that is the learner's file only up to the
+// end of the solution. Lines after it are in the hidden tests, and counted from their start
+fn locate(text: &str, file_name: &str, path: &Path, solution: &str) -> String {
+    let solution_lines = solution.lines().count();
+    let mut located = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(file_name) {
+        located.push_str(&rest[..start]);
+        rest = &rest[start + file_name.len()..];
+        let number: String = rest
+            .strip_prefix(':')
+            .unwrap_or_default()
+            .chars()
+            .take_while(char::is_ascii_digit)
+            .collect();
+        match number.parse::<usize>() {
+            Ok(line) if line > solution_lines => {
+                let line = (line + 1).saturating_sub(tests_start(solution)).max(1);
+                located.push_str(&format!("the hidden tests:{line}"));
+                rest = &rest[1 + number.len()..];
+            }
+            _ => located.push_str(&path.display().to_string()),
+        }
+    }
+    located.push_str(rest);
+    located
+}
+
+pub fn check(exercise: &Exercise, solution: &str) -> Result<Outcome, String> {
+    let scratch = Scratch::new("exercise")?;
+    let program = format!("{solution}{HIDDEN_TESTS}{}\n}}\n", exercise.tests);
+    let file_name = format!("{}.rs", exercise.name);
+    scratch.write(&file_name, &program)?;
+    let binary = scratch.path().join("tests");
+    // compiled from inside the scratch directory, so the messages only name <exercise>.rs
+    let compiled = toolchain::rustc()
+        .args(["--test", "-A", "warnings", "--error-format=json", "-o"])
+        .arg(&binary)
+        .arg(&file_name)
+        .current_dir(scratch.path())
+        .output()
+        .map_err(|e| format!("cannot run rustc: {e}"))?;
+    if !compiled.status.success() {
+        return Ok(Outcome::CompileError(
+            String::from_utf8_lossy(&compiled.stderr).into_owned(),
+        ));
+    }
+
+    let mut tests = Command::new(&binary);
+    tests
+        .args(["--test-threads", "1", "--color", "never"])
+        .env("RUST_BACKTRACE", "0");
+    let ran = sandbox::run(&tests, &Limits::default())?;
+    match ran.outcome {
+        // failing tests exit with 101, that's still a normal test run
+        sandbox::Outcome::Timeout | sandbox::Outcome::OutOfMemory => {
+            Ok(Outcome::Stopped(ran.outcome))
+        }
+        _ => Ok(Outcome::Tested(parse_test_output(&ran.stdout))),
+    }
+}
+
+// reads libtest's output:
+//     test hidden_tests::keeps_none ... ok
+//     ---- hidden_tests::increments_some stdout ----
+//     thread 'hidden_tests::increments_some' panicked at ...
+fn parse_test_output(output: &str) -> TestRun {
+    let mut run = TestRun {
+        passed: Vec::new(),
+        failed: Vec::new(),
+    };
+    let mut failing = Vec::new();
+    for line in output.lines() {
+        let Some(rest) = line.strip_prefix("test ") else {
+            continue;
+        };
+        if let Some(name) = rest.strip_suffix(" ... ok") {
+            run.passed.push(short_name(name));
+        } else if let Some(name) = rest.strip_suffix(" ... FAILED") {
+            failing.push(name.to_string());
+        }
+    }
+    for name in failing {
+        let header = format!("---- {name} stdout ----");
+        let message = output
+            .split_once(&header)
+            .map(|(_, rest)| {
+                rest.lines()
+                    .skip(1)
+                    // the note about RUST_BACKTRACE only comes after the first panic, after
+                    // the last failing test libtest goes on with its list of failures
+                    .take_while(|line| {
+                        !line.starts_with("---- ")
+                            && !line.starts_with("note: ")
+                            && *line != "failures:"
+                    })
+                    .filter(|line| !line.trim().is_empty())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .unwrap_or_default();
+        run.failed.push((short_name(&name), message));
+    }
+    run
+}
+
+fn short_name(name: &str) -> String {
+    name.trim_start_matches("hidden_tests::").to_string()
+}
+
+// exercises
+pub fn list(_args: &[String]) -> Result<(), String> {
+    for exercise in EXERCISES {
+        println!("{:<22} ({})", exercise.name, exercise.lesson);
+        println!("    {}", exercise.prompt());
+    }
+    Ok(())
+}
+
+// start <exercise>
+pub fn start(args: &[String]) -> Result<(), String> {
+    let exercise = find(args.first().ok_or("usage: start <exercise>")?)?;
+    let path = exercise.solution_path();
+    println!("{}\n", exercise.prompt());
+    if path.exists() {
+        println!("{} already exists, keep working on it", path.display());
+        return Ok(());
+    }
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("cannot create {}: {e}", dir.display()))?;
+    }
+    fs::write(&path, exercise.starter)
+        .map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+    println!(
+        "wrote {}, run `localplayground check {}` when you're done",
+        path.display(),
+        exercise.name
+    );
+    Ok(())
+}
+
+// check <exercise> [solution file]
+pub fn command(args: &[String]) -> Result<(), String> {
+    let exercise = find(
+        args.first()
+            .ok_or("usage: check <exercise> [solution file]")?,
+    )?;
+    let path = args
+        .get(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| exercise.solution_path());
+    let solution = fs::read_to_string(&path).map_err(|e| {
+        format!(
+            "cannot read {}: {e}, run `localplayground start {}` first",
+            path.display(),
+            exercise.name
+        )
+    })?;
+
+    // the tests ran in a scratch directory, messages name the learner's file instead
+    let file_name = format!("{}.rs", exercise.name);
+    let locate = |text: &str| locate(text, &file_name, &path, &solution);
+    match check(exercise, &solution)? {
+        Outcome::CompileError(diagnostics) => {
+            progress::record_or_warn(Kind::Exercise, exercise.name, false);
+            print!("{}", locate(&explain::render_errors(&diagnostics)?));
+            Err(format!("{} doesn't compile yet", path.display()))
+        }
+        Outcome::Stopped(outcome) => {
+            progress::record_or_warn(Kind::Exercise, exercise.name, false);
+            Err(format!("the tests of {} {outcome}", exercise.name))
+        }
+        Outcome::Tested(run) => {
+            progress::record_or_warn(Kind::Exercise, exercise.name, run.failed.is_empty());
+            let total = run.passed.len() + run.failed.len();
+            println!(
+                "{}: {}/{total} tests passed",
+                exercise.name,
+                run.passed.len()
+            );
+            for name in &run.passed {
+                println!("ok    {name}");
+            }
+            for (name, message) in &run.failed {
+                println!("FAIL  {name}");
+                for line in locate(message).lines() {
+                    println!("      {line}");
+                }
+            }
+            match run.failed.len() {
+                0 => Ok(()),
+                n => Err(format!("{n} test(s) failed")),
+            }
+        }
+    }
+}
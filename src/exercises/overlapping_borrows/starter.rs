@@ -0,0 +1,31 @@
+// Ownership und Borrowing
+// make overlapping_mut_borrowing compile. The counter has to be incremented three times
+// and the returned String has to show the final count.
+
+pub struct Counter {
+    name: String,
+    counter: u32,
+}
+
+pub fn new_counter(name: String) -> &'static mut Counter {
+    Box::leak(Box::new(Counter { name, counter: 0 }))
+}
+
+pub fn increment(counter: &mut Counter) {
+    counter.counter += 1;
+}
+
+pub fn as_string(counter: &Counter) -> String {
+    format!("{}: {}", counter.name, counter.counter)
+}
+
+pub fn overlapping_mut_borrowing() -> String {
+    let cnt = new_counter("my counter".to_string());
+    increment(cnt);
+    increment(cnt);
+    let cnt_ref1 = &(*cnt);
+    let cnt_ref2 = &(*cnt);
+    as_string(cnt_ref1);
+    increment(cnt); // this fails, because cnt_ref2 is still borrowing cnt
+    as_string(cnt_ref2)
+}
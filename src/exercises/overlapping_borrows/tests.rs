@@ -0,0 +1,11 @@
+#[test]
+fn counts_three_times() {
+    assert_eq!(overlapping_mut_borrowing(), "my counter: 3");
+}
+
+#[test]
+fn helpers_still_work() {
+    let counter = new_counter("other".to_string());
+    increment(counter);
+    assert_eq!(as_string(counter), "other: 1");
+}
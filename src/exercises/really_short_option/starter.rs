@@ -0,0 +1,8 @@
+// Structs, Enums
+// really_short_option should add one to the value, and give back None if there is none.
+// Make it compile without giving up the ? operator.
+
+pub fn really_short_option(option: Option<i32>) -> Option<i32> {
+    let a = option?; // this returns None instantly, if the Opion is not of kind Some(i)
+    a + 1
+}
@@ -0,0 +1,14 @@
+#[test]
+fn increments_some() {
+    assert_eq!(really_short_option(Some(41)), Some(42));
+}
+
+#[test]
+fn keeps_none() {
+    assert_eq!(really_short_option(None), None);
+}
+
+#[test]
+fn negative_numbers() {
+    assert_eq!(really_short_option(Some(-1)), Some(0));
+}
@@ -0,0 +1,95 @@
+/*
+    Macro expansion
+*/
+// `localplayground expand <lesson | file.rs> [function]` shows code after every macro
+// is expanded, the way `cargo expand` does it: with rustc -Zunpretty=expanded.
+// -Z options are for nightly compilers only. A stable rustc accepts them with
+// RUSTC_BOOTSTRAP=1 (that's how Rust builds itself), which is fine for looking at code,
+// we never build anything with it. If neither works, we say so.
+
+use std::path::{Path, PathBuf};
+use std::process::Output;
+
+use crate::lessons;
+use crate::source;
+use crate::toolchain::{self, Scratch};
+
+fn unpretty(file: &Path, bootstrap: bool) -> Result<Output, String> {
+    let mut rustc = toolchain::rustc();
+    rustc.args(["--crate-type", "lib", "-Zunpretty=expanded"]);
+    if bootstrap {
+        rustc.env("RUSTC_BOOTSTRAP", "1");
+    }
+    rustc
+        .arg(file)
+        .output()
+        .map_err(|e| format!("cannot run rustc: {e}"))
+}
+
+fn expand(file: &Path) -> Result<String, String> {
+    let mut output = unpretty(file, false)?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !output.status.success() && stderr.contains("only accepted on the nightly compiler") {
+        output = unpretty(file, true)?;
+    }
+    // the lessons use names from the playground (Lesson, super::), rustc complains about
+    // them only after the expansion is printed, so errors only count without output
+    if output.stdout.is_empty() {
+        return Err(format!(
+            "rustc can't expand {}:\n{}",
+            file.display(),
+            String::from_utf8_lossy(&output.stderr).trim_end()
+        ));
+    }
+    let expanded = String::from_utf8_lossy(&output.stdout);
+    // the prelude rustc adds to every crate
+    Ok(expanded
+        .lines()
+        .skip_while(|line| {
+            line.starts_with("#![feature(prelude_import)]")
+                || line.starts_with("extern crate std")
+                || line.starts_with("#[prelude_import]")
+                || line.starts_with("use std::prelude::")
+                || line.starts_with("#[macro_use]")
+        })
+        .map(|line| format!("{line}\n"))
+        .collect())
+}
+
+// expand <lesson | file.rs> [function]
+pub fn command(args: &[String]) -> Result<(), String> {
+    let target = args
+        .first()
+        .ok_or("usage: expand <lesson | file.rs> [function]")?;
+    let scratch = Scratch::new("expand")?;
+    let file = match lessons::find(target) {
+        // under its own name and next to the data files, so the lesson's include_str!
+        // calls find what they find in src/lessons
+        Some(lesson) => {
+            for (name, contents) in lessons::DATA {
+                scratch.write(&format!("data/{name}"), contents)?;
+            }
+            scratch.write(&format!("{}.rs", lesson.name), lesson.source)?
+        }
+        None if target.ends_with(".rs") => PathBuf::from(target),
+        None => {
+            return Err(format!(
+                "`{target}` is neither a lesson nor a .rs file, see `localplayground list`"
+            ))
+        }
+    };
+    let expanded = expand(&file)?;
+
+    let Some(wanted) = args.get(1) else {
+        print!("{expanded}");
+        return Ok(());
+    };
+    let function = source::functions(&expanded)
+        .into_iter()
+        .find(|function| function.name == wanted)
+        .ok_or_else(|| format!("{target} has no function `{wanted}`"))?;
+    for (_, line) in function.lines {
+        println!("{line}");
+    }
+    Ok(())
+}
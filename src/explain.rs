@@ -0,0 +1,332 @@
+/*
+    Explaining compiler errors
+*/
+// rustc's texts are written for everybody, the lessons already explain most errors a
+// learner runs into with an example. We read rustc's JSON diagnostics, look up the
+// lesson function that teaches the error code and print our explanation next to
+// rustc's message, together with the example from the lesson.
+// `localplayground explain <file.rs>` compiles a file like that,
+// `localplayground explain E0382` only explains the code.
+// The explanations are in the catalogues as error.<code>.
+
+use std::fs;
+use std::path::Path;
+
+use crate::compile_fail::SNIPPETS;
+use crate::i18n;
+use crate::json;
+use crate::lessons;
+use crate::source;
+use crate::toolchain::{self, Scratch};
+
+pub struct Topic {
+    pub code: &'static str,
+    pub lesson: &'static str,
+    pub function: &'static str,
+}
+
+// where the lessons teach what the error is about, ordered by code
+pub static TOPICS: &[Topic] = &[
+    Topic {
+        code: "E0004",
+        lesson: "structs_enums",
+        function: "this_enum",
+    },
+    Topic {
+        code: "E0005",
+        lesson: "patterns",
+        function: "refutability",
+    },
+    Topic {
+        code: "E0072",
+        lesson: "smart_pointers",
+        function: "recursive_list",
+    },
+    Topic {
+        code: "E0106",
+        lesson: "lifetimes",
+        function: "longest",
+    },
+    Topic {
+        code: "E0210",
+        lesson: "traits",
+        function: "conditional_implementation",
+    },
+    Topic {
+        code: "E0277",
+        lesson: "traits",
+        function: "generic_highest",
+    },
+    Topic {
+        code: "E0308",
+        lesson: "data_types",
+        function: "data_types",
+    },
+    Topic {
+        code: "E0369",
+        lesson: "structs_enums",
+        function: "options",
+    },
+    Topic {
+        code: "E0373",
+        lesson: "concurrency",
+        function: "spawning",
+    },
+    Topic {
+        code: "E0382",
+        lesson: "ownership",
+        function: "moving",
+    },
+    Topic {
+        code: "E0384",
+        lesson: "control_flow",
+        function: "if_else_decisions",
+    },
+    Topic {
+        code: "E0499",
+        lesson: "ownership",
+        function: "borrows_mutable",
+    },
+    Topic {
+        code: "E0502",
+        lesson: "ownership",
+        function: "overlapping_borrowing",
+    },
+    Topic {
+        code: "E0505",
+        lesson: "ownership",
+        function: "borrowing",
+    },
+    Topic {
+        code: "E0515",
+        lesson: "lifetimes",
+        function: "longest",
+    },
+    Topic {
+        code: "E0594",
+        lesson: "concurrency",
+        function: "arc_mutex",
+    },
+    Topic {
+        code: "E0596",
+        lesson: "ownership",
+        function: "mutable_borrowing",
+    },
+    Topic {
+        code: "E0597",
+        lesson: "lifetimes",
+        function: "longest",
+    },
+    Topic {
+        code: "E0599",
+        lesson: "oop",
+        function: "typestate",
+    },
+    Topic {
+        code: "E0716",
+        lesson: "patterns",
+        function: "decomposing",
+    },
+];
+
+pub fn topic(code: &str) -> Option<&'static Topic> {
+    TOPICS.iter().find(|topic| topic.code == code)
+}
+
+impl Topic {
+    pub fn explanation(&self) -> &'static str {
+        i18n::text(&format!("error.{}", self.code)).unwrap_or("")
+    }
+
+    // the function as it is in the lesson, with its place in the source
+    pub fn example(&self) -> Option<String> {
+        let lesson = lessons::find(self.lesson)?;
+        let function = source::functions(lesson.source)
+            .into_iter()
+            .find(|function| function.name == self.function)?;
+        let (first, _) = *function.lines.first()?;
+        let mut example = format!("src/lessons/{}.rs:{first}\n", self.lesson);
+        for (_, line) in &function.lines {
+            example.push_str(&format!("    {line}\n"));
+        }
+        Some(example)
+    }
+
+    // the broken version, if the compile-fail harness has one
+    fn snippets(&self) -> Vec<&'static str> {
+        SNIPPETS
+            .iter()
+            .filter(|snippet| snippet.expected == self.code)
+            .map(|snippet| snippet.name)
+            .collect()
+    }
+}
+
+pub struct Diagnostic {
+    pub level: String,
+    pub message: String,
+    pub code: Option<String>,
+    // the primary span, line numbers start at 1
+    pub line: Option<usize>,
+    // the message as rustc would have printed it
+    pub rendered: String,
+}
+
+// rustc writes one JSON object per diagnostic to stderr, other lines are skipped
+pub fn diagnostics(stderr: &str) -> Result<Vec<Diagnostic>, String> {
+    let mut diagnostics = Vec::new();
+    for line in stderr.lines().filter(|line| line.starts_with('{')) {
+        let value = json::parse(line)?;
+        let text = |key: &str| {
+            value
+                .get(key)
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string()
+        };
+        let primary = value
+            .get("spans")
+            .map(|spans| spans.as_array())
+            .unwrap_or_default()
+            .iter()
+            .find(|span| span.get("is_primary").and_then(|p| p.as_bool()) == Some(true));
+        diagnostics.push(Diagnostic {
+            level: text("level"),
+            message: text("message"),
+            code: value
+                .get("code")
+                .and_then(|code| code.get("code"))
+                .and_then(|code| code.as_str())
+                .map(str::to_string),
+            line: primary
+                .and_then(|span| span.get("line_start"))
+                .and_then(|line| line.as_usize()),
+            rendered: text("rendered"),
+        });
+    }
+    Ok(diagnostics)
+}
+
+// rustc's message, then ours with the lesson example
+pub fn render(diagnostic: &Diagnostic) -> String {
+    let mut text = diagnostic.rendered.clone();
+    if let Some(topic) = diagnostic.code.as_deref().and_then(topic) {
+        text.push_str(&format!("\n{}: {}\n", topic.code, topic.explanation()));
+        if let Some(example) = topic.example() {
+            text.push_str(&format!(
+                "\nsee {}::{} in {example}",
+                topic.lesson, topic.function
+            ));
+        }
+        for snippet in topic.snippets() {
+            text.push_str(&format!(
+                "broken on purpose: localplayground compile-fail {snippet} --show\n"
+            ));
+        }
+        text.push('\n');
+    }
+    text
+}
+
+// rustc's JSON output as text for the learner, warnings are left out and so is
+// `aborting due to previous error`, which has no place in the code
+pub fn render_errors(stderr: &str) -> Result<String, String> {
+    Ok(diagnostics(stderr)?
+        .iter()
+        .filter(|diagnostic| diagnostic.level == "error" && diagnostic.line.is_some())
+        .map(render)
+        .collect())
+}
+
+// a single line that points to the lesson, for places where the whole text is too much
+pub fn hint(code: &str) -> Option<String> {
+    let topic = topic(code)?;
+    Some(format!(
+        "{code} is explained in {}::{}, see `localplayground explain {code}`",
+        topic.lesson, topic.function
+    ))
+}
+
+fn explain_file(path: &Path) -> Result<(), String> {
+    let code =
+        fs::read_to_string(path).map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+    let scratch = Scratch::new("explain")?;
+    let file = scratch.write("main.rs", &code)?;
+    let output = toolchain::rustc()
+        .args([
+            "--crate-type",
+            "lib",
+            "--emit=metadata",
+            "--error-format=json",
+        ])
+        .args(["-A", "warnings", "--out-dir"])
+        .arg(scratch.path())
+        .arg(&file)
+        .output()
+        .map_err(|e| format!("cannot run rustc: {e}"))?;
+    if output.status.success() {
+        println!("{} compiles, nothing to explain", path.display());
+        return Ok(());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    // the scratch file name means nothing to the learner
+    let file_name = file.display().to_string();
+    let shown = path.display().to_string();
+    print!("{}", render_errors(&stderr)?.replace(&file_name, &shown));
+
+    let errors: Vec<Diagnostic> = diagnostics(&stderr)?
+        .into_iter()
+        .filter(|diagnostic| diagnostic.level == "error" && diagnostic.line.is_some())
+        .collect();
+    println!("{} error(s) in {shown}", errors.len());
+    for error in errors {
+        println!(
+            "    line {}: {} {}",
+            error.line.unwrap_or_default(),
+            error.code.as_deref().unwrap_or("     "),
+            error.message
+        );
+    }
+    Ok(())
+}
+
+fn explain_code(code: &str) -> Result<(), String> {
+    let topic = topic(code)
+        .ok_or_else(|| format!("no lesson explains {code} yet, try `rustc --explain {code}`"))?;
+    println!("{}: {}", topic.code, topic.explanation());
+    if let Some(example) = topic.example() {
+        println!("\nsee {}::{} in {example}", topic.lesson, topic.function);
+    }
+    for snippet in topic.snippets() {
+        println!("broken on purpose: localplayground compile-fail {snippet} --show");
+    }
+    Ok(())
+}
+
+// explain [file.rs | EXXXX], without an argument it lists the codes we explain
+pub fn command(args: &[String]) -> Result<(), String> {
+    match args.first() {
+        Some(code) if code.starts_with('E') && !code.ends_with(".rs") => explain_code(code),
+        Some(file) => explain_file(Path::new(file)),
+        None => {
+            let mut missing = 0;
+            for topic in TOPICS {
+                let found = topic.example().is_some();
+                missing += usize::from(!found);
+                println!(
+                    "{}  {}::{}{}",
+                    topic.code,
+                    topic.lesson,
+                    topic.function,
+                    if found { "" } else { "  (no such function)" }
+                );
+            }
+            match missing {
+                0 => Ok(()),
+                n => Err(format!(
+                    "{n} error code(s) point to functions that don't exist"
+                )),
+            }
+        }
+    }
+}
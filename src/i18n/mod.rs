@@ -0,0 +1,153 @@
+/*
+    Lesson texts in German and English
+*/
+// Titles, explanations and exercise prompts live in a message catalogue per language,
+// one `key = text` per line. The language comes from --lang, else from LANG,
+// everything that isn't German is shown in English.
+// `localplayground i18n` reports the keys that are missing in one of the catalogues.
+
+use std::env;
+use std::sync::OnceLock;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Lang {
+    De,
+    En,
+}
+
+pub const LANGS: [Lang; 2] = [Lang::De, Lang::En];
+
+impl Lang {
+    // accepts "de" as well as locales like "de_DE.UTF-8"
+    pub fn parse(code: &str) -> Option<Lang> {
+        match code.get(..2)?.to_ascii_lowercase().as_str() {
+            "de" => Some(Lang::De),
+            "en" => Some(Lang::En),
+            _ => None,
+        }
+    }
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Lang::De => "de",
+            Lang::En => "en",
+        }
+    }
+
+    fn catalogue(self) -> &'static str {
+        match self {
+            Lang::De => include_str!("de.txt"),
+            Lang::En => include_str!("en.txt"),
+        }
+    }
+
+    fn other(self) -> Lang {
+        match self {
+            Lang::De => Lang::En,
+            Lang::En => Lang::De,
+        }
+    }
+}
+
+static LANG: OnceLock<Lang> = OnceLock::new();
+
+// called once with the value of --lang, before anything is printed
+pub fn init(flag: Option<&str>) -> Result<(), String> {
+    let lang = match flag {
+        Some(code) => {
+            Lang::parse(code).ok_or_else(|| format!("unknown language `{code}`, use de or en"))?
+        }
+        None => from_env(),
+    };
+    let _ = LANG.set(lang);
+    Ok(())
+}
+
+fn from_env() -> Lang {
+    env::var("LANG")
+        .ok()
+        .and_then(|value| Lang::parse(&value))
+        .unwrap_or(Lang::En)
+}
+
+pub fn lang() -> Lang {
+    *LANG.get_or_init(from_env)
+}
+
+fn entries(lang: Lang) -> impl Iterator<Item = (&'static str, &'static str)> {
+    lang.catalogue()
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let (key, text) = line.split_once('=')?;
+            Some((key.trim(), text.trim()))
+        })
+}
+
+pub fn lookup(lang: Lang, key: &str) -> Option<&'static str> {
+    entries(lang).find(|(k, _)| *k == key).map(|(_, text)| text)
+}
+
+// the text in the current language, an untranslated key falls back to the other one
+pub fn text(key: &str) -> Option<&'static str> {
+    let lang = lang();
+    lookup(lang, key).or_else(|| lookup(lang.other(), key))
+}
+
+// `key`, `key.2`, `key.3` ... as long as there are more
+pub fn paragraphs(key: &str) -> Vec<&'static str> {
+    let mut paragraphs: Vec<&str> = text(key).into_iter().collect();
+    for n in 2.. {
+        match text(&format!("{key}.{n}")) {
+            Some(paragraph) => paragraphs.push(paragraph),
+            None => break,
+        }
+    }
+    paragraphs
+}
+
+// keys which have to exist, even if no catalogue has them yet
+fn required_keys() -> Vec<String> {
+    let lessons = crate::lessons::LESSONS.iter().flat_map(|lesson| {
+        [
+            format!("{}.title", lesson.name),
+            format!("{}.intro", lesson.name),
+        ]
+    });
+    let exercises = crate::exercises::EXERCISES
+        .iter()
+        .map(|exercise| format!("exercise.{}", exercise.name));
+    let errors = crate::explain::TOPICS
+        .iter()
+        .map(|topic| format!("error.{}", topic.code));
+    lessons.chain(exercises).chain(errors).collect()
+}
+
+// i18n: reports untranslated keys
+pub fn command(_args: &[String]) -> Result<(), String> {
+    let mut keys: Vec<String> = LANGS
+        .iter()
+        .flat_map(|&lang| entries(lang).map(|(key, _)| key.to_string()))
+        .chain(required_keys())
+        .collect();
+    keys.sort();
+    keys.dedup();
+
+    let mut missing = 0;
+    for key in &keys {
+        let absent: Vec<&str> = LANGS
+            .iter()
+            .filter(|&&lang| lookup(lang, key).is_none())
+            .map(|lang| lang.code())
+            .collect();
+        if !absent.is_empty() {
+            missing += 1;
+            println!("{key:<32} missing in {}", absent.join(", "));
+        }
+    }
+    println!("{} keys, {missing} not translated everywhere", keys.len());
+    match missing {
+        0 => Ok(()),
+        n => Err(format!("{n} key(s) need a translation")),
+    }
+}
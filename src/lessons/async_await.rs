@@ -0,0 +1,406 @@
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::{pin, Pin};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+
+use super::{Demo, Lesson};
+
+pub const LESSON: Lesson = Lesson {
+    name: "async_await",
+    source: include_str!("async_await.rs"),
+    requires: &["traits", "concurrency"],
+    demos: &[
+        Demo {
+            name: "lazy_futures",
+            run: lazy_futures,
+        },
+        Demo {
+            name: "executor",
+            run: executor,
+        },
+        Demo {
+            name: "timers_and_join",
+            run: timers_and_join,
+        },
+        Demo {
+            name: "pinning",
+            run: pinning,
+        },
+        Demo {
+            name: "cancellation",
+            run: cancellation,
+        },
+    ],
+};
+
+/*
+Async/await
+*/
+// async fn and async blocks don't run anything, they return a Future:
+// a state machine that gets a bit further each time it is polled
+// trait Future { type Output; fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output>; }
+// Poll::Pending means "not yet", the future promises to call the Waker from cx once
+// polling again is worth it. .await polls the inner future and passes Pending up.
+// std has the trait but nothing that polls, that's the job of a runtime like tokio.
+// We can't download tokio here, so mini_runtime below is a tiny one built from std.
+
+//      Futures are lazy
+fn lazy_futures() {
+    let future = async {
+        // only printed once the future is polled
+        println!("running");
+        40 + 2
+    };
+    println!("created"); // created
+
+    // polling by hand, Waker::noop because nobody has to be woken up
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    if let Poll::Ready(value) = future.as_mut().poll(&mut cx) {
+        println!("{value}"); // 42, the block has no .await, so it is done after one poll
+    }
+}
+
+//      A mini runtime
+// everything an executor needs, only with std:
+//  - block_on: polls one future on the current thread, parks the thread while it is Pending
+//  - Executor: a queue of tasks, a task's Waker puts it back into the queue
+//  - sleep: a timer future, join: runs two futures at once, timeout: cancels a future
+mod mini_runtime {
+    use std::cell::Cell;
+    use std::collections::VecDeque;
+    use std::future::Future;
+    use std::pin::{pin, Pin};
+    use std::sync::{Arc, Condvar, Mutex};
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::thread::{self, Thread};
+    use std::time::Duration;
+
+    // Wake is the safe way to make a Waker: anything in an Arc that knows what to do on wake
+    // (without it, a Waker is a RawWaker with a table of unsafe functions)
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    pub fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                // park may also return without an unpark, polling once too often is fine
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    type BoxedFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+    struct Queue {
+        tasks: Mutex<VecDeque<Arc<Task>>>,
+        ready: Condvar,
+    }
+
+    struct Task {
+        // None once the future is done
+        future: Mutex<Option<BoxedFuture>>,
+        queue: Arc<Queue>,
+    }
+
+    // the Waker of a task puts the task back into the queue, it may be called from any thread
+    // (a timer thread here), that's why Wake needs Send + Sync and the future has to be Send
+    impl Wake for Task {
+        fn wake(self: Arc<Self>) {
+            let queue = Arc::clone(&self.queue);
+            queue.tasks.lock().unwrap().push_back(self);
+            queue.ready.notify_one();
+        }
+    }
+
+    // single threaded: the tasks take turns on the thread that calls run
+    pub struct Executor {
+        queue: Arc<Queue>,
+        unfinished: Cell<usize>,
+    }
+
+    impl Executor {
+        pub fn new() -> Executor {
+            Executor {
+                queue: Arc::new(Queue {
+                    tasks: Mutex::new(VecDeque::new()),
+                    ready: Condvar::new(),
+                }),
+                unfinished: Cell::new(0),
+            }
+        }
+
+        pub fn spawn(&self, future: impl Future<Output = ()> + Send + 'static) {
+            let task = Arc::new(Task {
+                future: Mutex::new(Some(Box::pin(future))),
+                queue: Arc::clone(&self.queue),
+            });
+            self.unfinished.set(self.unfinished.get() + 1);
+            self.queue.tasks.lock().unwrap().push_back(task);
+        }
+
+        // until every task is done, a task that is never woken again keeps it waiting forever
+        pub fn run(&self) {
+            while self.unfinished.get() > 0 {
+                let task = {
+                    let mut tasks = self.queue.tasks.lock().unwrap();
+                    loop {
+                        if let Some(task) = tasks.pop_front() {
+                            break task;
+                        }
+                        // everything is Pending, sleep until some Waker pushes a task
+                        tasks = self.queue.ready.wait(tasks).unwrap();
+                    }
+                };
+                let waker = Waker::from(Arc::clone(&task));
+                let mut cx = Context::from_waker(&waker);
+                let mut slot = task.future.lock().unwrap();
+                // a task that was woken twice is in the queue twice, the second time
+                // it may already be done
+                if let Some(mut future) = slot.take() {
+                    match future.as_mut().poll(&mut cx) {
+                        Poll::Pending => *slot = Some(future),
+                        Poll::Ready(()) => self.unfinished.set(self.unfinished.get() - 1),
+                    }
+                }
+            }
+        }
+    }
+
+    // Pending once, and wakes itself right away, so the other tasks get a turn
+    pub struct YieldNow(bool);
+
+    pub fn yield_now() -> YieldNow {
+        YieldNow(false)
+    }
+
+    impl Future for YieldNow {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                return Poll::Ready(());
+            }
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    // the timer runs on its own thread and calls the Waker it got on the last poll
+    // (a real runtime has one timer for all futures, or asks the OS)
+    pub struct Sleep {
+        state: Arc<Mutex<(bool, Option<Waker>)>>,
+    }
+
+    pub fn sleep(duration: Duration) -> Sleep {
+        let state = Arc::new(Mutex::new((false, None::<Waker>)));
+        let for_timer = Arc::clone(&state);
+        thread::spawn(move || {
+            thread::sleep(duration);
+            let mut state = for_timer.lock().unwrap();
+            state.0 = true;
+            if let Some(waker) = state.1.take() {
+                waker.wake();
+            }
+        });
+        Sleep { state }
+    }
+
+    impl Future for Sleep {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            let mut state = self.state.lock().unwrap();
+            if state.0 {
+                return Poll::Ready(());
+            }
+            // the future may have moved to another task since the last poll
+            state.1 = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+
+    // polls both every time it is polled, until both are done
+    // the futures are boxed, so Join is Unpin and we don't need unsafe pin projections
+    pub struct Join<A: Future, B: Future> {
+        a: Pin<Box<A>>,
+        b: Pin<Box<B>>,
+        a_output: Option<A::Output>,
+        b_output: Option<B::Output>,
+    }
+
+    pub fn join<A: Future, B: Future>(a: A, b: B) -> Join<A, B> {
+        Join {
+            a: Box::pin(a),
+            b: Box::pin(b),
+            a_output: None,
+            b_output: None,
+        }
+    }
+
+    impl<A: Future, B: Future> Future for Join<A, B>
+    where
+        A::Output: Unpin,
+        B::Output: Unpin,
+    {
+        type Output = (A::Output, B::Output);
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let join = &mut *self;
+            // a finished future must not be polled again
+            if join.a_output.is_none() {
+                if let Poll::Ready(output) = join.a.as_mut().poll(cx) {
+                    join.a_output = Some(output);
+                }
+            }
+            if join.b_output.is_none() {
+                if let Poll::Ready(output) = join.b.as_mut().poll(cx) {
+                    join.b_output = Some(output);
+                }
+            }
+            match (join.a_output.take(), join.b_output.take()) {
+                (Some(a), Some(b)) => Poll::Ready((a, b)),
+                (a, b) => {
+                    join.a_output = a;
+                    join.b_output = b;
+                    Poll::Pending
+                }
+            }
+        }
+    }
+
+    // None if the time is up first, the future is dropped then and never polled again
+    pub struct Timeout<F: Future> {
+        future: Pin<Box<F>>,
+        timer: Sleep,
+    }
+
+    pub fn timeout<F: Future>(duration: Duration, future: F) -> Timeout<F> {
+        Timeout {
+            future: Box::pin(future),
+            timer: sleep(duration),
+        }
+    }
+
+    impl<F: Future> Future for Timeout<F> {
+        type Output = Option<F::Output>;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            if let Poll::Ready(output) = self.future.as_mut().poll(cx) {
+                return Poll::Ready(Some(output));
+            }
+            match Pin::new(&mut self.timer).poll(cx) {
+                Poll::Ready(()) => Poll::Ready(None),
+                Poll::Pending => Poll::Pending,
+            }
+        }
+    }
+}
+
+use mini_runtime::{block_on, join, sleep, timeout, yield_now, Executor};
+
+//      Tasks
+// a task is a future the executor owns, each .await that returns Pending gives the
+// other tasks a turn: cooperative, a task that never awaits blocks everyone
+// spawn wants Send futures, an Rc that is still used after an .await is kept in the
+// future and makes it !Send (rustc: future cannot be sent between threads safely)
+fn executor() {
+    let executor = Executor::new();
+    let log = Arc::new(Mutex::new(Vec::new()));
+    for name in ["a", "b"] {
+        let log = Arc::clone(&log);
+        executor.spawn(async move {
+            for step in 1..=2 {
+                log.lock().unwrap().push(format!("{name}{step}"));
+                yield_now().await;
+            }
+        });
+    }
+    executor.run();
+    println!("{}", log.lock().unwrap().join(" ")); // a1 b1 a2 b2
+}
+
+//      Timers and join
+// sleep doesn't block the thread like thread::sleep, it returns Pending
+// so join can wait for both timers at the same time
+fn timers_and_join() {
+    let start = Instant::now();
+    let (a, b) = block_on(join(
+        async {
+            sleep(Duration::from_millis(50)).await;
+            "slow"
+        },
+        async {
+            sleep(Duration::from_millis(20)).await;
+            "fast"
+        },
+    ));
+    println!("{a} {b}"); // slow fast, in the order of the arguments, not in the order they finished
+
+    // about 50ms and not 70ms, both slept at the same time
+    println!("{:?}", start.elapsed() < Duration::from_millis(70));
+}
+
+//      Pinning
+// an async block keeps its local variables in the future, a reference to one of them that
+// lives across an .await points into the future itself
+// if the future moved after the first poll, that reference would point to the old place,
+// so poll takes Pin<&mut Self>: the future stays where it is until it is dropped
+// Box::pin pins on the heap, pin! on the stack
+// types that don't care about moving are Unpin (nearly all of them), Pin changes nothing for them
+// async blocks are not Unpin, Pin::new doesn't work for them (compile-fail pin_async_block)
+fn pinning() {
+    let future = async {
+        let text = String::from("borrowed across an await");
+        let borrowed = &text; // a reference into the future's own state
+        sleep(Duration::from_millis(5)).await;
+        borrowed.len()
+    };
+    // the Box may move around, the future inside doesn't
+    let boxed: Pin<Box<dyn Future<Output = usize>>> = Box::pin(future);
+    println!("{}", block_on(boxed)); // 24
+
+    let mut number = 5;
+    let pinned = Pin::new(&mut number); // i32 is Unpin
+    *pinned.get_mut() += 1; // so we get the &mut back
+    println!("{number}"); // 6
+}
+
+//      Cancellation
+// there is no cancel(): a future that is dropped is never polled again, it stops at the
+// .await where it was waiting and its variables are dropped
+// so every .await is a point where the rest of the function might not run
+struct Noisy(&'static str);
+
+impl Drop for Noisy {
+    fn drop(&mut self) {
+        println!("{} dropped", self.0);
+    }
+}
+
+fn cancellation() {
+    let polls = Cell::new(0);
+    let slow = async {
+        let _noisy = Noisy("slow future"); // dropped when the future is dropped
+        polls.set(polls.get() + 1);
+        sleep(Duration::from_secs(2)).await; // the timer thread keeps sleeping, nobody waits for it
+        println!("never printed");
+    };
+    let result = block_on(timeout(Duration::from_millis(20), slow)); // prints `slow future dropped`
+    println!("{result:?} {}", polls.get()); // None 1
+
+    let fast = block_on(timeout(Duration::from_secs(2), async { 42 }));
+    println!("{fast:?}"); // Some(42)
+}
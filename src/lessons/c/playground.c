@@ -0,0 +1,57 @@
+/* The C side of the unsafe_ffi lesson, built by build.rs with the system cc. */
+
+#include <stdint.h>
+#include <stdlib.h>
+#include <string.h>
+
+/* signed overflow is undefined behaviour in C, as int64_t two int32_t always fit */
+int64_t pg_add(int32_t a, int32_t b) {
+    return (int64_t)a + b;
+}
+
+/* C doesn't know slices, a pointer and a length it is */
+int64_t pg_sum(const int32_t *values, size_t len) {
+    int64_t sum = 0;
+    for (size_t i = 0; i < len; i++) {
+        sum += values[i];
+    }
+    return sum;
+}
+
+/* an opaque type: Rust only ever sees a pointer to it */
+struct pg_counter {
+    uint32_t count;
+};
+
+struct pg_counter *pg_counter_new(void) {
+    return calloc(1, sizeof(struct pg_counter));
+}
+
+uint32_t pg_counter_increment(struct pg_counter *counter) {
+    return ++counter->count;
+}
+
+void pg_counter_free(struct pg_counter *counter) {
+    free(counter);
+}
+
+/* writes "Hello, <name>!" into out, at most len bytes including the 0,
+   returns the length the whole greeting needs like snprintf does */
+size_t pg_greet(const char *name, char *out, size_t len) {
+    const char *start = "Hello, ";
+    size_t needed = strlen(start) + strlen(name) + 2; /* the ! and the 0 */
+    if (len > 0) {
+        size_t written = 0;
+        for (const char *part = start; *part && written + 1 < len; part++) {
+            out[written++] = *part;
+        }
+        for (const char *part = name; *part && written + 1 < len; part++) {
+            out[written++] = *part;
+        }
+        if (written + 1 < len) {
+            out[written++] = '!';
+        }
+        out[written] = '\0';
+    }
+    return needed;
+}
@@ -0,0 +1,102 @@
+use super::{Demo, Lesson};
+
+pub const LESSON: Lesson = Lesson {
+    name: "closures",
+    source: include_str!("closures.rs"),
+    requires: &["traits"],
+    demos: &[
+        Demo {
+            name: "add_one",
+            run: add_one,
+        },
+        Demo {
+            name: "example_closure",
+            run: example_closure,
+        },
+        Demo {
+            name: "passing_a_func",
+            run: passing_a_func,
+        },
+        Demo {
+            name: "sort",
+            run: sort,
+        },
+    ],
+};
+
+/*
+Closures
+*/
+// Closures are functions saved in a variable or passed to a function
+// comparison
+fn add_one_v1(x: u32) -> u32 {
+    x + 1
+}
+
+fn add_one() {
+    let add_one_v2 = |x: u32| -> u32 { x + 1 };
+    let add_one_v3 = |x| x + 1;
+    let add_one_v4 = |x| x + 1;
+    println!(
+        "{} {} {} {}",
+        add_one_v1(1),
+        add_one_v2(1),
+        add_one_v3(1),
+        add_one_v4(1)
+    ); // 2 2 2 2
+}
+
+// example usage
+fn example_closure() {
+    let mut list = vec![1, 2, 3];
+    println!("Before defining closure: {:?}", list);
+
+    let mut borrows_mutably = || list.push(7);
+
+    borrows_mutably();
+    println!("After calling closure: {:?}", list);
+}
+
+// pass to a function
+fn pass_me_a_func<F>(function: F)
+where
+    F: Fn(i32) -> i32,
+{
+    let a = function(5);
+    println!("{}", a); // 25
+}
+
+fn passing_a_func() {
+    let func = |i: i32| -> i32 { i * i };
+    pass_me_a_func(func);
+}
+
+//also possible use
+#[derive(Debug)]
+struct Rectangle {
+    width: u32,
+    height: u32,
+}
+
+fn sort() {
+    let mut list = [
+        Rectangle {
+            width: 10,
+            height: 1,
+        },
+        Rectangle {
+            width: 3,
+            height: 5,
+        },
+        Rectangle {
+            width: 7,
+            height: 12,
+        },
+    ];
+
+    list.sort_by_key(|r| r.width);
+    println!("{:?}", list);
+    list.sort_by_key(|r| r.width * r.height); // any key the closure computes works
+    println!("{:?}", list);
+}
+// another example is found at iterators with filter() and map()
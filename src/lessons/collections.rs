@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+
+use super::{Demo, Lesson};
+
+pub const LESSON: Lesson = Lesson {
+    name: "collections",
+    source: include_str!("collections.rs"),
+    requires: &["ownership"],
+    demos: &[
+        Demo {
+            name: "vectors",
+            run: vectors,
+        },
+        Demo {
+            name: "hash_map",
+            run: hash_map,
+        },
+    ],
+};
+
+/*
+Collections
+*/
+// stack, appendable array, resizable array
+fn vectors() {
+    let mut vector = vec![1, 2, 3, 4, 5, 9];
+    let vector2 = vec![80, 35, 658];
+    vector.extend(vector2); // append to it
+    println!("{:?}", vector); // "[1, 2, 3, 4, 5, 9, 80, 35, 658]"
+
+    let vector3: Vec<String> = Vec::new();
+    println!("{}", vector3.len()); // 0
+}
+
+fn hash_map() {
+    let mut reviews = HashMap::<String, String>::new(); // type can also be inferred
+    reviews.insert(
+        "Adventures of Huckleberry Finn".to_string(),
+        "My favorite book.".to_string(),
+    );
+    reviews.insert(
+        "Grimms' Fairy Tales".to_string(),
+        "Masterpiece.".to_string(),
+    );
+    reviews.insert(
+        "Pride and Prejudice".to_string(),
+        "Very enjoyable.".to_string(),
+    );
+    println!("{}", reviews.len()); // 3, the length
+
+    reviews.remove("Pride and Prejudice"); // removes Element with key "Pri.."
+    println!("{}", reviews.contains_key("Pride and Prejudice")); // false, was true before
+
+    let to_find = ["Pride and Prejudice", "Adventures of Huckleberry Finn"];
+    for &book in &to_find {
+        // borrow because else it would be consumed, in this case with &str that has copy trait it is not necessary
+        match reviews.get(book) {
+            Some(review) => println!("{book}: {review}"),
+            None => println!("{book} is unreviewed."),
+        }
+    }
+
+    // entry gives us the place of a key, whether it is occupied or not
+    let review = reviews
+        .entry("Masterpiece".to_string())
+        .or_insert("not reviewed yet".to_string());
+    println!("{review}");
+    // the map is gone once we return, `localplayground reviews` keeps reviews in a file
+}
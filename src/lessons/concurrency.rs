@@ -0,0 +1,192 @@
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use super::{Demo, Lesson};
+
+pub const LESSON: Lesson = Lesson {
+    name: "concurrency",
+    source: include_str!("concurrency.rs"),
+    requires: &["closures", "smart_pointers"],
+    demos: &[
+        Demo {
+            name: "spawning",
+            run: spawning,
+        },
+        Demo {
+            name: "send_sync",
+            run: send_sync,
+        },
+        Demo {
+            name: "arc_mutex",
+            run: arc_mutex,
+        },
+        Demo {
+            name: "channels",
+            run: channels,
+        },
+        Demo {
+            name: "scoped_threads",
+            run: scoped_threads,
+        },
+    ],
+};
+
+/*
+Concurrency
+*/
+// Fearless concurrency: the ownership and borrowing rules also rule out data races,
+// so most mistakes with threads are compile errors instead of bugs that show up once a week
+
+//      Threads
+// thread::spawn takes a closure and runs it on a new OS thread
+// the thread may outlive the function that started it, so the closure has to own
+// everything it uses -> move (compile-fail thread_borrows_local)
+fn spawning() {
+    let name = String::from("worker");
+    // name is moved into the thread
+    let handle = thread::spawn(move || format!("{name} done"));
+    // println!("{name}"); // borrow of moved value
+    println!("{}", handle.join().unwrap()); // worker done
+
+    // join waits for the thread and hands back what the closure returned,
+    // Err if the thread panicked
+    let handles: Vec<_> = (1..=3).map(|i| thread::spawn(move || i * i)).collect();
+    let squares: Vec<i32> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    println!("{squares:?}"); // "[1, 4, 9]", always in this order because we join in order
+}
+
+//      Send and Sync
+// Send: the value may be moved to another thread
+// Sync: a &T may be used by several threads at once (T is Sync if &T is Send)
+// both are marker traits the compiler implements automatically, a type is Send if all
+// of its fields are
+// Rc is neither: its reference count is a plain number, two threads cloning at the same
+// time could lose an increment and free the value too early
+// RefCell isn't Sync, its borrow counter isn't thread safe either
+// so the Rc<RefCell<List>> from smart_pointers::combined_rc_refcell can't go to another
+// thread (compile-fail rc_not_send)
+fn must_be_send<T: Send>(value: T) -> T {
+    value
+}
+
+fn send_sync() {
+    let counted = must_be_send(Arc::new(Mutex::new(vec![1, 2])));
+    // must_be_send(std::rc::Rc::new(5)); // `Rc<i32>` cannot be sent between threads safely
+    let for_thread = Arc::clone(&counted);
+    thread::spawn(move || for_thread.lock().unwrap().push(3))
+        .join()
+        .unwrap();
+    println!("{:?}", counted.lock().unwrap()); // "[1, 2, 3]"
+
+    // Arc is the atomic Rc: the count is updated with atomic instructions, which is slower,
+    // so Rc is still the right choice on a single thread
+}
+
+//      Arc<Mutex<T>>
+// the thread safe version of Rc<RefCell<T>>:
+// Arc = several owners across threads, Mutex = mutation through a shared reference
+// lock() blocks until no other thread holds the lock, the guard unlocks when it is dropped
+// lock() returns Err if another thread panicked while holding it (the mutex is "poisoned")
+// Arc alone only gives shared access, mutating needs the Mutex (compile-fail arc_without_mutex)
+mod shared {
+    use std::sync::{Arc, Mutex};
+
+    // the same List as in smart_pointers, only with Arc and Mutex
+    #[derive(Debug)]
+    pub enum List {
+        Cons(i32, Arc<Mutex<List>>),
+        Nil,
+    }
+
+    impl List {
+        // every element has to be locked to look at it
+        pub fn sum(&self) -> i32 {
+            match self {
+                List::Cons(value, next) => value + next.lock().unwrap().sum(),
+                List::Nil => 0,
+            }
+        }
+    }
+}
+
+fn arc_mutex() {
+    use shared::List::{Cons, Nil};
+    let list = Arc::new(Mutex::new(Cons(
+        5,
+        Arc::new(Mutex::new(Cons(10, Arc::new(Mutex::new(Nil))))),
+    )));
+    let for_thread = Arc::clone(&list);
+    thread::spawn(move || {
+        if let Cons(ref mut v, _) = *for_thread.lock().unwrap() {
+            *v += 3;
+        }
+    })
+    .join()
+    .unwrap();
+    if let Cons(v, _) = &*list.lock().unwrap() {
+        println!("{v}"); // 8
+    }
+    println!("{}", list.lock().unwrap().sum()); // 18
+
+    // a counter shared by ten threads, no increment is lost
+    let counter = Arc::new(Mutex::new(0));
+    let handles: Vec<_> = (0..10)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || *counter.lock().unwrap() += 1)
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    println!("{}", *counter.lock().unwrap()); // 10
+
+    // locking two mutexes in different orders on two threads is a deadlock, Rust doesn't
+    // prevent that
+}
+
+//      Channels
+// "Do not communicate by sharing memory; instead, share memory by communicating."
+// mpsc = multiple producer, single consumer
+// send moves the value into the channel, the sending thread can't use it anymore
+fn channels() {
+    let (tx, rx) = mpsc::channel();
+    let second = tx.clone(); // a second producer
+    thread::spawn(move || {
+        for word in ["one", "two", "three"] {
+            tx.send(word.to_string()).unwrap();
+        }
+    });
+    thread::spawn(move || second.send(String::from("four")).unwrap());
+
+    // the loop ends once every sender is dropped, here when both threads are done
+    let mut received: Vec<String> = rx.iter().collect();
+    // messages of one producer stay in order, between producers there is no order
+    received.sort();
+    println!("{}", received.join(" ")); // four one three two
+}
+
+//      Scoped threads
+// threads started in thread::scope are joined at the end of the scope,
+// so they can borrow local variables, no Arc and no move needed
+// the borrowing rules still apply: many & or one &mut (compile-fail scoped_data_race)
+fn scoped_threads() {
+    let mut numbers = vec![1, 2, 3, 4, 5, 6];
+    let total: i32 = numbers.iter().sum();
+    thread::scope(|s| {
+        // chunks_mut hands out non overlapping &mut, one per thread
+        for chunk in numbers.chunks_mut(2) {
+            s.spawn(move || chunk.iter_mut().for_each(|n| *n *= 10));
+        }
+    });
+    println!("{numbers:?}"); // "[10, 20, 30, 40, 50, 60]"
+
+    let text = String::from("shared by reference");
+    let lengths = thread::scope(|s| {
+        let words = s.spawn(|| text.split_whitespace().count());
+        let bytes = s.spawn(|| text.len());
+        (words.join().unwrap(), bytes.join().unwrap())
+    });
+    println!("{lengths:?} {total}"); // "(3, 19) 21"
+}
@@ -0,0 +1,192 @@
+use super::{Demo, Lesson};
+
+pub const LESSON: Lesson = Lesson {
+    name: "control_flow",
+    source: include_str!("control_flow.rs"),
+    requires: &["data_types"],
+    demos: &[
+        Demo {
+            name: "if_else_decisions",
+            run: if_else_decisions,
+        },
+        Demo {
+            name: "infinite_loop",
+            run: infinite_loop,
+        },
+        Demo {
+            name: "while_loop",
+            run: while_loop,
+        },
+        Demo {
+            name: "for_loop",
+            run: for_loop,
+        },
+        Demo {
+            name: "match_statement",
+            run: match_statement,
+        },
+        Demo {
+            name: "if_let",
+            run: if_let,
+        },
+        Demo {
+            name: "while_let",
+            run: while_let,
+        },
+    ],
+};
+
+/*
+        Kontrollstrukturen und Schleifen
+
+*/
+
+fn if_else_decisions() {
+    // without mut, changing n doesn't compile (compile-fail immutable_reassign)
+    let mut n = 5;
+    if 1 == n {
+        n += 2;
+    } else if n < 6 {
+        n -= 1;
+    } else {
+        n += 3;
+    }
+    println!("{}", n); // 4
+}
+
+fn infinite_loop() {
+    let mut count = 0;
+    loop {
+        count += 1;
+
+        if count == 3 {
+            println!("three");
+            continue; // Skip the rest of this iteration
+        }
+
+        if count == 5 {
+            println!("OK, that's enough");
+            break; // Exit this loop
+        }
+    }
+}
+
+fn while_loop() {
+    let mut n = 0;
+    while n < 5 {
+        println!("{}", n);
+        n += 1;
+    }
+}
+
+fn for_loop() {
+    // prints until 99
+    for i in 1..100 {
+        println!("{}", i)
+    }
+
+    // prints until 100
+    for i in 1..=100 {
+        println!("{}", i)
+    }
+}
+
+//      Match
+fn match_statement() {
+    for i in 0..10 {
+        match i {
+            0 => println!("low"),
+            2 | 4 | 6 => println!("low and even"), // 2 or 4 or 6
+            7..=8 => println!("High {}", i), // range: 2..4 is not allowed here, has to be 2..=4
+            i if i > 8 => println!("Very High {}", i), // match Guard
+            // other => println!("Number {}", other)  // takes the missing case but also binds it to accessable variable name // Binding
+            _ => println!("Anything else"), // catch else, needs to be included!
+        }
+    }
+
+    let boolean = true;
+    // Match is an expression too, can assing values
+    let binary = match boolean {
+        false => 0, // 0 returned (to binary)
+        true => 1,  // 1 returned
+    };
+    println!("{}", binary); // 1
+
+    let triple = (0, -2, 3);
+
+    // Match TUPLE
+    match triple {
+        // Destructure the second and third elements
+        (0, y, z) => println!("First is `0`, `y` is {:?}, and `z` is {:?}", y, z),
+        (1, ..) => println!("First is `1` and the rest doesn't matter"),
+        (.., 2) => println!("last is `2` and the rest doesn't matter"),
+        (3, .., 4) => println!("First is `3`, last is `4`, and the rest doesn't matter"), // `..` can be used to ignore the rest of the tuple
+        _ => println!("It doesn't matter what they are"), // `_` means don't bind the value to a variable
+    }
+
+    // match ENUMS
+    #[allow(dead_code)]
+    enum Color {
+        // These 3 are specified solely by their name.
+        Red,
+        Blue,
+        Green,
+    }
+    let color = Color::Red;
+    match color {
+        Color::Red => println!("The color is Red!"),
+        Color::Blue => println!("The color is Blue!"),
+        Color::Green => println!("The color is Green!"),
+    }
+
+    // match STRUCTS
+    struct Foo {
+        x: (u32, u32),
+        y: u32,
+    }
+
+    let value = Foo { x: (1, 2), y: 3 };
+    match value {
+        Foo { x: (1, b), y } => println!("First of x is 1, b = {},  y = {} ", b, y),
+        Foo { y: 2, x: i } => println!("y is 2, i = {:?}", i),
+        // you can destructure structs and rename the variables,
+        // the order is not important
+        Foo { .. } => println!("Anything else"), // the patterns above don't cover every Foo
+    }
+}
+
+fn if_let() {
+    let number = Some(7);
+    let letter: Option<i32> = None;
+
+    // The `if let` construct reads: "if `let` destructures `number` into
+    // `Some(i)`, evaluate the block (`{}`).
+    if let Some(i) = number {
+        println!("Matched {:?}!", i);
+    }
+    // If you need to specify a failure, use an else:
+    if let Some(i) = letter {
+        println!("Matched {:?}!", i);
+    } else {
+        // Destructure will fail and print this
+        println!("Didn't match a number. Let's go with a letter!");
+    }
+}
+
+fn while_let() {
+    // Make `optional` of type `Option<i32>`
+    let mut optional = Some(0);
+
+    // This reads: "while `let` destructures `optional` into
+    // `Some(i)`, evaluate the block (`{}`). Else `break`.
+    while let Some(i) = optional {
+        // aka as long as optional is equivalent to Some with any i as value.
+        if i > 9 {
+            println!("Greater than 9, quit!");
+            optional = None;
+        } else {
+            println!("`i` is `{:?}`. Try again.", i);
+            optional = Some(i + 1);
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use super::{Demo, Lesson};
+
+pub const LESSON: Lesson = Lesson {
+    name: "data_types",
+    source: include_str!("data_types.rs"),
+    requires: &[],
+    demos: &[
+        Demo {
+            name: "data_types",
+            run: data_types,
+        },
+        Demo {
+            name: "operations",
+            run: || println!("returned {}", operations()),
+        },
+        Demo {
+            name: "shadowing_and_scope",
+            run: shadowing_and_scope,
+        },
+        Demo {
+            name: "ranges",
+            run: ranges,
+        },
+        Demo {
+            name: "static_and_const",
+            run: static_and_const,
+        },
+        Demo {
+            name: "type_name",
+            run: type_name,
+        },
+    ],
+};
+
+/*
+    Datentypen
+*/
+fn data_types() {
+    // declared with let
+    let int: i32 = 1;
+    // types are inferred, don't need to annotate
+    // mutability needs to be declared
+    let mut int2 = 2_000_456; // _ for readability
+
+    let float: f32 = 2.4;
+    let boolean1: bool = true;
+    let string: &str = "oh yeah"; // str itself has no known size, so we hold it behind a reference (compile-fail str_by_value)
+    let v: Vec<u8> = vec![1, 2, 3];
+
+    // compiler type inference would now recognise int2 as u64
+    int2 += 2u64;
+    int2 += 1;
+    println!("{int} {int2} {float} {boolean1} {string} {v:?}");
+}
+
+/*
+Variablen und Operationen
+*/
+
+fn operations() -> i32 {
+    //  you can also have a return type declared
+    let int1: i32 = 45;
+    let int2 = 32;
+
+    // macro for printing
+    print!("{} ", int1 + int2);
+    print!("{} ", int1 - int2);
+    print!("{} ", int1 / int2);
+    print!("{} ", int1 * int2);
+    println!("{}", int1 % int2);
+    // checked arithmetic returns Some or None (later)
+    let a = int1.checked_mul(int2);
+    println!("{a:?}");
+    let a = int1.checked_add(int2);
+    println!("{a:?}");
+    let a = int1.checked_div(0);
+    println!("{a:?}"); // None, dividing by zero has no result
+    5 // returned value
+}
+
+fn shadowing_and_scope() {
+    let a = 1;
+    let mut b = 23;
+    {
+        let a = 42;
+        println!("{}", a); // 42, original a is shadowed
+        b *= 2
+    }
+    println!("{}", a); // 1
+    println!("{}", b); // 46, because b wasn't shadowed and still in scope
+}
+
+fn ranges() {
+    let range = 0..10; // from 0 to under 10 (9) , contains  start <= x < end
+    let range2 = std::ops::Range { start: 3, end: 5 }; // underlying commands
+    println!("{}", range.contains(&10)); // false
+    println!("{}", range.contains(&9)); // true, 9 is the last number in the range
+    println!("{:?}", range2);
+
+    // Slice, access index-wise with a range
+    let name = String::from("jeremias");
+    println!("{}", &name[0..1]); // "j"
+    println!("{}", &name[0..3]); // "jer"
+}
+
+fn static_and_const() {
+    // const, used for constant values not to be changed or reassigned, const mut is illegal
+    const INTEGER_VALUE: i32 = 25;
+    // static, used for global values, can also be mutable
+    static INTEGER_VALUE2: i32 = 26;
+    static mut INTEGER_VALUE3: i32 = 26;
+
+    // changing a static mut is unsafe, as every thread could access it at the same time
+    let value3 = unsafe {
+        INTEGER_VALUE3 += 1;
+        INTEGER_VALUE3
+    };
+    println!("{INTEGER_VALUE} {INTEGER_VALUE2} {value3}");
+}
+
+// Also interesting for borrowing:
+// Some objects need longer lifetime (statics),
+// we can allocate memory on the heap (not stack)
+// which will be freed after the owner is dropped.
+
+/*
+Other Random Stuff we used
+ */
+// random numbers need the rand crate, which we don't pull into the offline playground
+
+// Type name
+fn print_type_of<T>(identifier: &str, _: &T) {
+    println!(
+        "The type of '{identifier}' is '{}'",
+        std::any::type_name::<T>()
+    )
+}
+
+fn type_name() {
+    let my_variable = 1_0;
+    print_type_of("my_variable", &my_variable);
+}
@@ -0,0 +1,120 @@
+use std::fs::File;
+use std::panic;
+
+use super::{Demo, Lesson};
+
+pub const LESSON: Lesson = Lesson {
+    name: "errors",
+    source: include_str!("errors.rs"),
+    requires: &["structs_enums", "patterns"],
+    demos: &[
+        Demo {
+            name: "unrecoverable",
+            run: unrecoverable,
+        },
+        Demo {
+            name: "recoverable",
+            run: recoverable,
+        },
+        Demo {
+            name: "panic_hook",
+            run: panic_hook,
+        },
+        Demo {
+            name: "result_enum",
+            run: result_enum,
+        },
+        Demo {
+            name: "result_example",
+            run: || println!("{:?} {:?}", result_example(Ok(21)), result_example(Err(0))),
+        },
+    ],
+};
+
+/*
+        Fehlerbehandlung
+*/
+
+// Recoverable / Unrecoverable
+fn unrecoverable() {
+    let a = [1, 5, 2, 7, 3, 8, 3];
+    let index = a.len() + 9;
+    let b = a[index]; // of course this panics and we can't handle it
+    println!("{}", b);
+}
+fn recoverable() {
+    let file = File::open("gfg.txt");
+    // this will give a printed error of the Result<> that file has
+    // we could prevent it with a match to Ok() and Err()
+    println!("{:?}", file);
+}
+//Backtrace wofür?
+// to find the origin of a panic
+
+// the macro: panic!
+// will stop the program and clear the stack
+fn panic_hook() {
+    // setting a custom panic action
+    panic::set_hook(Box::new(|x| {
+        println!("Custom panic hook {x:?}");
+        // a real program could end here with a custom code: std::process::exit(42)
+    }));
+    let result = panic::catch_unwind(|| panic!("oh no"));
+    println!("{}", result.is_err()); // true
+    let _ = panic::take_hook(); // back to the default hook
+}
+// Standard-Types from std-Lib (not sure what is meant, but)
+/*  growable Strings like: "hello world"
+growable vectors: [1, 2, 3]
+optional types: Option<i32>
+error handling types: Result<i32, i32>
+heap allocated pointers: Box<i32> */
+
+// unwrap and expect, when is it used?
+//
+// Especially with Result<T,E>
+// T and E are generics. T can contain any type of value, E can be any error.
+//enum Result<T, E> {
+//    Ok(T),
+//   Err(E),
+//}
+
+// the values are known here on purpose, clippy would rather have us skip the unwrapping
+#[allow(
+    clippy::unnecessary_literal_unwrap,
+    clippy::unnecessary_lazy_evaluations
+)]
+fn result_enum() {
+    let success: Result<i32, i32> = Ok(45); // types need to be defined
+    let error: Result<i32, i32> = Err(0);
+
+    println!("{}", success.is_ok()); // true
+    println!("{}", success.is_err()); // false
+
+    // also for Options!!
+    // not good to use, at least those that panic and have no alternate behaviour
+    let value = success.unwrap(); // panics if success is Err() or None
+    let value2 = success.expect("Error message"); // same as unwrap with a message
+    let value3 = error.expect_err("OK error message"); // panics if error is Ok() or Some()
+    println!("{value} {value2} {value3}");
+
+    // Better use these
+    let edge_case = 5;
+    let value4 = error.unwrap_or(0); // if there is an error, use the given value instead of panicking
+    let value5 = error.unwrap_or_default(); // if there is an error, use the default value of the data type
+    let value6 = error.unwrap_or_else(|_| edge_case); // if there is an error, evalutate the Closure and return that value
+    println!("{value4} {value5} {value6}");
+}
+
+fn result_example(i: Result<i32, i32>) -> Result<i32, i32> {
+    match i {
+        Ok(i) => {
+            println!("{}", i);
+            Ok(i * 2)
+        }
+        Err(..) => {
+            println!("Error");
+            Err(1)
+        }
+    }
+} // Option<T> is also there for error handling
@@ -0,0 +1,73 @@
+use super::{Demo, Lesson};
+
+pub const LESSON: Lesson = Lesson {
+    name: "iterators",
+    source: include_str!("iterators.rs"),
+    requires: &["collections"],
+    demos: &[Demo {
+        name: "iterators",
+        run: iterators,
+    }],
+};
+
+/*
+Iterators
+*/
+fn iterators() {
+    let mut names = vec!["Bob", "Frank", "Ferris"];
+    // prints Bob, Frank and Ferris
+    for name in names.iter() {
+        println!("{}", name);
+    }
+
+    for name in names.iter_mut() {
+        // this iterator allows mutable borrowing of each element
+        if *name == "Frank" {
+            *name = "ahahaha"; // value can be changed
+        }
+        println!("{}", name);
+    }
+
+    // iterators can be used very comfortably with map/filter and collect
+    // filter() will keep the value if the closure inside evaluates to true
+    let names1: Vec<&str> = names
+        .iter()
+        .filter(|name| match name.chars().next() {
+            Some(i) => i != 'B',
+            None => true,
+        })
+        .cloned()
+        .collect(); // we need to clone, because filter returns an iterator over references to elements of the original iterator, so we receive &&str not &str
+
+    for name in names.into_iter() {
+        // this iterator consumes the collection. It will not be available after this iteration
+        println!("{}", name);
+    }
+    // println!("{}", names[0]);  will FAIL! because into_iter takes ownership (compile-fail into_iter_moved)
+
+    // map() -> work with values and return them (potentially modified) as a copied iterator
+    // collect() the whole iterator
+    let mut names2: Vec<String> = names1.iter().map(|name| name.to_string()).collect();
+    // There also are
+    // map_or("defaul value", |item| item_mapped)
+    // map_or_else(|else| else_func(), |foo| foo_mapped)
+
+    for name in names2.iter_mut() {
+        // this iterator allows mutable borrowing of each element
+        let other = "no way its you, ";
+        *name = format!("{}{}", other, name); // format! returns a String not str (hence the String::from in the vector declaration)
+        println!("{}", name);
+    }
+
+    let _lazy = names2.iter_mut().map(|name| name.push('l')); // iterators are lazy and do nothing. use last() to consume the iterator
+    names2.iter_mut().map(|name| name.push('l')).last();
+    println!("{:?}", names2);
+
+    // counting entries with fold()
+    let count = names2.iter().fold(0, |count, _| count + 1);
+    println!("{}", count); // 2
+
+    //also relevant
+    //iter().max()
+    //iter.min()
+}
@@ -0,0 +1,68 @@
+use super::{Demo, Lesson};
+
+pub const LESSON: Lesson = Lesson {
+    name: "lifetimes",
+    source: include_str!("lifetimes.rs"),
+    requires: &["ownership"],
+    demos: &[
+        Demo {
+            name: "static_counter",
+            run: static_counter,
+        },
+        Demo {
+            name: "longest",
+            run: || println!("{}", longest("jeremias", "ferris")),
+        },
+    ],
+};
+
+/*
+        Lifetime
+        you need to specify lifetime parameters for functions or structs that use references
+        &i32        // a reference
+        &'a i32     // a reference with an explicit lifetime
+        &'a mut i32 // a mutable reference with an explicit lifetime
+        Lifetime Elision Rules (automatic lifetime from compiler)
+        [1] for each parameter the compiler adds one lifetime parameter
+        [2] only one parameter -> return value has lifetime of that parameter
+        [3] if there is self as input parameter, the output value has lifetime of self
+*/
+pub struct Counter {
+    name: String,
+    counter: u32,
+}
+
+//static global lifetime, here, the counter lives forever
+// a reference to a local would die with the function (compile-fail static_ref_to_local),
+// so we leak a Box to really get 'static
+pub fn new_counter(name: String) -> &'static mut Counter {
+    Box::leak(Box::new(Counter { name, counter: 0 }))
+}
+
+pub fn increment(counter: &mut Counter) {
+    counter.counter += 1;
+}
+
+pub fn as_string(counter: &Counter) -> String {
+    format!("{}: {}", counter.name, counter.counter)
+}
+
+fn static_counter() {
+    let counter = new_counter("forever".to_string());
+    increment(counter);
+    println!("{}", as_string(counter)); // forever: 1
+}
+// anonymous lifetime TODO
+
+// this code fails without lifetime annotation, because at compile time we don't know if x or y is returned and so the borrow checker fails to know when which borrow ends
+// adding it fixes the problem
+fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if x.len() > y.len() {
+        x
+    } else {
+        y
+    }
+}
+
+// returning a reference to a String created inside the function doesn't work,
+// it is dropped after longest() and unassociated with the lifetime 'a (compile-fail longest_dangling)
@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+
+use super::{Demo, Lesson};
+
+pub const LESSON: Lesson = Lesson {
+    name: "macros",
+    source: include_str!("macros.rs"),
+    requires: &["collections", "traits"],
+    demos: &[
+        Demo {
+            name: "hashmap_literal",
+            run: hashmap_literal,
+        },
+        Demo {
+            name: "maximum",
+            run: maximum,
+        },
+        Demo {
+            name: "declaring_lessons",
+            run: declaring_lessons,
+        },
+        Demo {
+            name: "derive_like",
+            run: derive_like,
+        },
+    ],
+};
+
+/*
+Macros
+*/
+// println!, format!, vec!, panic!: the ! means macro, code that writes code at compile time
+// what a macro can do that a function can't:
+// take any number of arguments, look at the tokens instead of values, write items (structs, impls)
+// but they are harder to read and the errors are worse, so a function is the first choice
+
+//      macro_rules!
+// declarative macros match the tokens against patterns, like match does with values
+// $name:fragment captures a piece of code, fragments are expr, ident, ty, tt, literal, ...
+// $( ... ),* repeats, zero or more times separated by commas (+ for one or more)
+// the first arm that matches is used, the others aren't tried
+macro_rules! hashmap {
+    // $(,)? allows a trailing comma
+    ($($key:expr => $value:expr),* $(,)?) => {{
+        // ::std so it works in modules that don't import HashMap
+        #[allow(unused_mut)] // hashmap! {} inserts nothing
+        let mut map = ::std::collections::HashMap::new();
+        $(map.insert($key, $value);)*
+        map
+    }};
+}
+// the outer braces belong to macro_rules, the inner ones make the expansion a block expression
+
+fn hashmap_literal() {
+    // the reviews of collections::hash_map, without three insert calls
+    let reviews: HashMap<String, String> = hashmap! {
+        "Adventures of Huckleberry Finn".to_string() => "My favorite book.".to_string(),
+        "Grimms' Fairy Tales".to_string() => "Masterpiece.".to_string(),
+        "Pride and Prejudice".to_string() => "Very enjoyable.".to_string(),
+    };
+    println!("{}", reviews.len()); // 3
+    println!("{}", reviews["Grimms' Fairy Tales"]); // Masterpiece.
+    let empty: HashMap<i32, i32> = hashmap! {};
+    println!("{}", empty.is_empty()); // true
+}
+
+//      Repetition and recursion
+// a macro may call itself, here with one argument less each time
+macro_rules! max {
+    ($x:expr) => {
+        $x
+    };
+    ($x:expr, $($rest:expr),+) => {{
+        // bound once, writing $x twice would evaluate it twice
+        let first = $x;
+        let rest = max!($($rest),+);
+        if first > rest {
+            first
+        } else {
+            rest
+        }
+    }};
+}
+
+fn maximum() {
+    println!("{}", max!(3)); // 3
+    println!("{}", max!(3, 42, 7)); // 42
+    println!("{}", max!("pear", "apple")); // pear, anything that is PartialOrd works
+
+    // max!(); // no rules expected this token, the error points into the macro
+
+    // hygiene: the `first` inside of max! is a different variable than this one
+    let first = 1;
+    println!("{}", max!(first, 0)); // 1
+}
+
+//      A DSL for lessons
+// the registration at the top of every lesson repeats every name twice, a macro can write it
+// stringify! turns tokens into a &str, concat! glues literals together at compile time
+macro_rules! lesson {
+    ($name:ident requires [$($required:ident),*] { $($demo:ident),* $(,)? }) => {
+        Lesson {
+            name: stringify!($name),
+            source: include_str!(concat!(stringify!($name), ".rs")),
+            requires: &[$(stringify!($required)),*],
+            demos: &[$(Demo {
+                name: stringify!($demo),
+                run: $demo,
+            }),*],
+        }
+    };
+}
+
+// the same as LESSON above
+const DECLARED: Lesson = lesson!(macros requires [collections, traits] {
+    hashmap_literal,
+    maximum,
+    declaring_lessons,
+    derive_like,
+});
+
+fn declaring_lessons() {
+    println!(
+        "{} requires {}",
+        DECLARED.name,
+        DECLARED.requires.join(" and ")
+    ); // macros requires collections and traits
+    let demos: Vec<&str> = DECLARED.demos.iter().map(|demo| demo.name).collect();
+    println!("{}", demos.join(" ")); // hashmap_literal maximum declaring_lessons derive_like
+    println!("{}", DECLARED.source == LESSON.source); // true
+}
+
+//      Procedural macros
+// #[derive(Debug)], attributes like #[test] and function-like ones like sqlx::query! are
+// procedural macros: Rust functions that get the tokens of an item and return new tokens.
+// They need their own crate with proc-macro = true (and mostly syn and quote), so there's
+// none in this playground. macro_rules! can do a small derive as well: it gets the whole
+// struct, writes it out again and adds an impl
+macro_rules! with_field_names {
+    ($(#[$meta:meta])* struct $name:ident { $($field:ident: $ty:ty),* $(,)? }) => {
+        $(#[$meta])*
+        struct $name {
+            $($field: $ty),*
+        }
+
+        impl $name {
+            fn field_names() -> &'static [&'static str] {
+                &[$(stringify!($field)),*]
+            }
+        }
+    };
+}
+
+with_field_names! {
+    #[derive(Debug)]
+    struct Review {
+        title: String,
+        stars: u8,
+    }
+}
+
+fn derive_like() {
+    println!("{}", Review::field_names().join(" ")); // title stars
+    let review = Review {
+        title: String::from("Masterpiece."),
+        stars: 5,
+    };
+    println!("{} {}", review.title, review.stars); // Masterpiece. 5
+
+    // the derive(Debug) was passed through
+    println!("{review:?}");
+}
+
+//      Looking at the expansion
+// `localplayground expand macros maximum` prints maximum the way the compiler sees it
+// after all macros are expanded, println! included
@@ -0,0 +1,207 @@
+/*
+    Lesson registry
+*/
+// Every section of the playground lives in its own module and registers itself
+// with a name (used on the command line), its own source (for the tools that
+// read the comments), the lessons it builds on and the demo functions that can be run.
+// Titles and explanations are in the catalogues in src/i18n, keyed by the name.
+
+use crate::i18n;
+
+mod async_await;
+mod closures;
+mod collections;
+mod concurrency;
+mod control_flow;
+mod data_types;
+mod errors;
+mod iterators;
+mod lifetimes;
+mod macros;
+mod oop;
+mod order_book;
+mod ownership;
+mod patterns;
+mod portfolio;
+mod retail;
+mod smart_pointers;
+mod strings;
+mod structs_enums;
+mod traits;
+mod unsafe_ffi;
+
+pub struct Lesson {
+    pub name: &'static str,
+    pub source: &'static str,
+    // names of the lessons that should be done first, see `localplayground path`
+    pub requires: &'static [&'static str],
+    pub demos: &'static [Demo],
+}
+
+pub struct Demo {
+    pub name: &'static str,
+    pub run: fn(),
+}
+
+// in the order they are meant to be read
+pub static LESSONS: &[Lesson] = &[
+    data_types::LESSON,
+    ownership::LESSON,
+    control_flow::LESSON,
+    structs_enums::LESSON,
+    patterns::LESSON,
+    collections::LESSON,
+    iterators::LESSON,
+    strings::LESSON,
+    traits::LESSON,
+    errors::LESSON,
+    lifetimes::LESSON,
+    closures::LESSON,
+    smart_pointers::LESSON,
+    concurrency::LESSON,
+    async_await::LESSON,
+    oop::LESSON,
+    macros::LESSON,
+    unsafe_ffi::LESSON,
+    portfolio::LESSON,
+    order_book::LESSON,
+    retail::LESSON,
+];
+
+// the files lessons read with include_str!("data/…"), for the tools that compile a
+// lesson's source on its own, like `localplayground expand`
+pub static DATA: &[(&str, &str)] = &[
+    ("prices.csv", include_str!("data/prices.csv")),
+    ("session.txt", include_str!("data/session.txt")),
+    (
+        "session_trades.txt",
+        include_str!("data/session_trades.txt"),
+    ),
+];
+
+// demos that panic to show what a panic looks like, `run` doesn't count them as failed
+static PANICS_ON_PURPOSE: &[(&str, &str)] = &[("errors", "unrecoverable")];
+
+pub fn find(name: &str) -> Option<&'static Lesson> {
+    LESSONS.iter().find(|lesson| lesson.name == name)
+}
+
+impl Lesson {
+    pub fn title(&self) -> &'static str {
+        i18n::text(&format!("{}.title", self.name)).unwrap_or(self.name)
+    }
+
+    pub fn intro(&self) -> Vec<&'static str> {
+        i18n::paragraphs(&format!("{}.intro", self.name))
+    }
+
+    pub fn demo(&self, name: &str) -> Option<&'static Demo> {
+        self.demos.iter().find(|demo| demo.name == name)
+    }
+
+    pub fn panics_on_purpose(&self, demo: &Demo) -> bool {
+        PANICS_ON_PURPOSE.contains(&(self.name, demo.name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::process::Command;
+
+    use super::{DATA, LESSONS};
+    use crate::compile_fail::{self, Verdict, SNIPPETS};
+    use crate::sandbox::{self, Limits, Outcome};
+    use crate::verify;
+
+    // `verify` runs the demos through the playground binary, which `cargo test` doesn't
+    // build. Instead the test binary starts itself again with only `one_demo` and the
+    // demo in this variable, and one_demo does what `localplayground run` would do.
+    // The markers separate the demo's output from what libtest prints around it.
+    const DEMO: &str = "LOCALPLAYGROUND_TEST_DEMO";
+    const START: &str = "==== demo output starts ====";
+    const END: &str = "==== demo output ends ====";
+
+    #[test]
+    fn one_demo() {
+        let Ok(demo) = env::var(DEMO) else {
+            return;
+        };
+        let args: Vec<String> = demo.split(' ').map(String::from).collect();
+        println!("\n{START}");
+        let result = crate::run(&args);
+        println!("\n{END}");
+        // an Err is a panic that isn't on the list of PANICS_ON_PURPOSE
+        result.unwrap();
+    }
+
+    #[test]
+    fn every_demo_runs_and_prints_what_its_comments_say() {
+        let exe = env::current_exe().unwrap();
+        let mut failures = Vec::new();
+        for lesson in LESSONS {
+            for demo in lesson.demos {
+                let mut command = Command::new(&exe);
+                command
+                    .args(["lessons::tests::one_demo", "--exact", "--nocapture"])
+                    .env(DEMO, format!("{} {}", lesson.name, demo.name));
+                let ran = sandbox::run(&command, &Limits::default()).unwrap();
+                if ran.outcome != Outcome::Ok {
+                    failures.push(format!("{}::{} {}", lesson.name, demo.name, ran.outcome));
+                    continue;
+                }
+                let output: String = ran
+                    .stdout
+                    .lines()
+                    .skip_while(|line| *line != START)
+                    .skip(1)
+                    .take_while(|line| *line != END)
+                    .map(|line| format!("{line}\n"))
+                    .collect();
+                for mismatch in verify::compare(lesson, demo, &output) {
+                    failures.push(format!(
+                        "src/lessons/{}.rs:{} ({}): expected `{}`, printed `{}`",
+                        mismatch.lesson,
+                        mismatch.expectation.line,
+                        mismatch.demo,
+                        mismatch.expectation.text,
+                        mismatch.actual.as_deref().unwrap_or("nothing more"),
+                    ));
+                }
+            }
+        }
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn every_data_file_of_a_lesson_is_in_data() {
+        const INCLUDE: &str = "include_str!(\"data/";
+        for lesson in LESSONS {
+            for (start, _) in lesson.source.match_indices(INCLUDE) {
+                let rest = &lesson.source[start + INCLUDE.len()..];
+                let file = rest.split('"').next().unwrap();
+                assert!(
+                    DATA.iter().any(|(name, _)| *name == file),
+                    "{} reads data/{file}, which is missing from lessons::DATA",
+                    lesson.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn every_compile_fail_snippet_fails_with_its_error() {
+        let mut failures = Vec::new();
+        for snippet in SNIPPETS {
+            match compile_fail::check(snippet).unwrap() {
+                (Verdict::Fails, _) => {}
+                (Verdict::Compiles, _) => failures.push(format!("{} compiles", snippet.name)),
+                (Verdict::WrongErrors(codes), _) => failures.push(format!(
+                    "{} expected {}, rustc reported {codes:?}",
+                    snippet.name, snippet.expected
+                )),
+            }
+        }
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+}
@@ -0,0 +1,470 @@
+use super::{Demo, Lesson};
+
+pub const LESSON: Lesson = Lesson {
+    name: "oop",
+    source: include_str!("oop.rs"),
+    requires: &["traits", "smart_pointers"],
+    demos: &[
+        Demo {
+            name: "encapsulation",
+            run: encapsulation,
+        },
+        Demo {
+            name: "polymorphism",
+            run: polymorphism,
+        },
+        Demo {
+            name: "state_pattern",
+            run: state_pattern,
+        },
+        Demo {
+            name: "typestate",
+            run: typestate,
+        },
+        Demo {
+            name: "same_rules",
+            run: same_rules,
+        },
+    ],
+};
+
+/*
+OOP
+*/
+
+// Rust is not strictly an OOP language.
+// Objects: structs and enums have data and methods (impl blocks), that's close enough
+
+//      Encapsulation
+// fields are private to their module unless they are pub
+// so the invariants are kept by the methods, nobody else can touch the data
+mod averaged {
+    // invariant: average is always the average of list
+    pub struct AveragedCollection {
+        list: Vec<i32>,
+        average: f64,
+    }
+
+    impl AveragedCollection {
+        pub fn new() -> AveragedCollection {
+            AveragedCollection {
+                list: vec![],
+                average: 0.0,
+            }
+        }
+
+        pub fn add(&mut self, value: i32) {
+            self.list.push(value);
+            self.update_average();
+        }
+
+        pub fn remove(&mut self) -> Option<i32> {
+            let value = self.list.pop()?; // nothing removed, nothing to update
+            self.update_average();
+            Some(value)
+        }
+
+        // reading is fine, there is just no way to set the average from outside
+        pub fn average(&self) -> f64 {
+            self.average
+        }
+
+        // private, the callers don't have to remember it
+        fn update_average(&mut self) {
+            let total: i32 = self.list.iter().sum();
+            self.average = match self.list.len() {
+                0 => 0.0,
+                len => total as f64 / len as f64,
+            };
+        }
+    }
+}
+
+fn encapsulation() {
+    let mut collection = averaged::AveragedCollection::new();
+    collection.add(1);
+    collection.add(2);
+    collection.add(6);
+    println!("{}", collection.average()); // 3
+    collection.remove();
+    println!("{}", collection.average()); // 1.5
+
+    // collection.average = 10.0; // field `average` is private, doesn't compile
+    // if the list was a HashSet tomorrow, the callers wouldn't notice
+}
+
+//      Inheritance
+// there is no inheritance of data, traits share behaviour instead
+// default methods are what a "base class" would implement, types can override them
+mod abstract_module {
+    pub trait Abstract {
+        fn describe(&self) -> String;
+
+        // only uses describe, so every type gets it for free
+        fn summary(&self) -> String {
+            format!("a shape that is {}", self.describe())
+        }
+    }
+}
+
+//      Polymorphism
+// via Dynamic Dispatch and Trait Objects (or generics with trait bounds, see traits)
+use abstract_module::Abstract;
+
+struct Circle;
+struct Square;
+
+impl Abstract for Circle {
+    fn describe(&self) -> String {
+        String::from("round")
+    }
+}
+
+impl Abstract for Square {
+    fn describe(&self) -> String {
+        String::from("four corners")
+    }
+
+    // overriding the default
+    fn summary(&self) -> String {
+        String::from("a square, with four corners")
+    }
+}
+
+fn polymorphism() {
+    // different types behind the same trait object
+    let shapes: Vec<Box<dyn Abstract>> = vec![Box::new(Circle), Box::new(Square)];
+    for shape in &shapes {
+        println!("{}", shape.describe());
+    }
+    for shape in &shapes {
+        println!("{}", shape.summary());
+    }
+}
+
+// The workflow of a blog post, implemented twice:
+//  - a post starts as a draft, only drafts can be edited
+//  - a draft has to be reviewed, approving the review publishes the post
+//  - rejecting it sends the post back to draft
+//  - only a published post shows its text
+
+//      State pattern
+// the post has a state object, each state knows what the next one is
+// the post itself doesn't know the rules, new states don't change the Post
+mod state_post {
+    pub struct Post {
+        // Option only so the state can be taken out for a moment, see transition
+        state: Option<Box<dyn State>>,
+        content: String,
+    }
+
+    impl Post {
+        pub fn new() -> Post {
+            Post {
+                state: Some(Box::new(Draft)),
+                content: String::new(),
+            }
+        }
+
+        pub fn add_text(&mut self, text: &str) {
+            if self.state.as_ref().is_some_and(|state| state.editable()) {
+                self.content.push_str(text);
+            }
+        }
+
+        // the state decides, not the post
+        pub fn content(&self) -> &str {
+            self.state
+                .as_ref()
+                .map_or("", |state| state.content(&self.content))
+        }
+
+        pub fn status(&self) -> &'static str {
+            self.state.as_ref().map_or("", |state| state.name())
+        }
+
+        pub fn request_review(&mut self) {
+            self.transition(|state| state.request_review());
+        }
+
+        pub fn approve(&mut self) {
+            self.transition(|state| state.approve());
+        }
+
+        pub fn reject(&mut self) {
+            self.transition(|state| state.reject());
+        }
+
+        // the transitions consume the old state (self: Box<Self>), a field can't be moved
+        // out of &mut self, but take() can leave None in its place
+        fn transition(&mut self, change: impl FnOnce(Box<dyn State>) -> Box<dyn State>) {
+            if let Some(state) = self.state.take() {
+                self.state = Some(change(state));
+            }
+        }
+    }
+
+    // private, outside of the module nobody can make up a state
+    trait State {
+        fn name(&self) -> &'static str;
+        // a step that isn't allowed returns the same state, so it is ignored
+        fn request_review(self: Box<Self>) -> Box<dyn State>;
+        fn approve(self: Box<Self>) -> Box<dyn State>;
+        fn reject(self: Box<Self>) -> Box<dyn State>;
+
+        fn editable(&self) -> bool {
+            false
+        }
+
+        fn content<'a>(&self, _content: &'a str) -> &'a str {
+            ""
+        }
+    }
+
+    struct Draft;
+    struct PendingReview;
+    struct Published;
+
+    impl State for Draft {
+        fn name(&self) -> &'static str {
+            "draft"
+        }
+        fn request_review(self: Box<Self>) -> Box<dyn State> {
+            Box::new(PendingReview)
+        }
+        fn approve(self: Box<Self>) -> Box<dyn State> {
+            self
+        }
+        fn reject(self: Box<Self>) -> Box<dyn State> {
+            self
+        }
+        fn editable(&self) -> bool {
+            true
+        }
+    }
+
+    impl State for PendingReview {
+        fn name(&self) -> &'static str {
+            "pending review"
+        }
+        fn request_review(self: Box<Self>) -> Box<dyn State> {
+            self
+        }
+        fn approve(self: Box<Self>) -> Box<dyn State> {
+            Box::new(Published)
+        }
+        fn reject(self: Box<Self>) -> Box<dyn State> {
+            Box::new(Draft)
+        }
+    }
+
+    impl State for Published {
+        fn name(&self) -> &'static str {
+            "published"
+        }
+        fn request_review(self: Box<Self>) -> Box<dyn State> {
+            self
+        }
+        fn approve(self: Box<Self>) -> Box<dyn State> {
+            self
+        }
+        fn reject(self: Box<Self>) -> Box<dyn State> {
+            self
+        }
+        fn content<'a>(&self, content: &'a str) -> &'a str {
+            content
+        }
+    }
+}
+
+fn state_pattern() {
+    let mut post = state_post::Post::new();
+    post.add_text("I ate a salad for lunch today");
+    println!("{}: {:?}", post.status(), post.content()); // draft: ""
+    post.request_review();
+    println!("{}: {:?}", post.status(), post.content()); // pending review: ""
+    post.approve();
+    println!("{}: {:?}", post.status(), post.content()); // published: "I ate a salad for lunch today"
+}
+
+//      Typestate
+// every state is its own type and the transitions take self and return the next type
+// a step that isn't allowed is a method that doesn't exist, so the compiler finds it
+// not very "OOP", but it's what you would do in Rust
+mod typed_post {
+    pub struct Post {
+        content: String,
+    }
+
+    pub struct DraftPost {
+        content: String,
+    }
+
+    pub struct PendingReviewPost {
+        content: String,
+    }
+
+    impl Post {
+        // a new post is a draft, there is no other way to get one
+        // clippy expects new() to return Self, here that's the point
+        #[allow(clippy::new_ret_no_self)]
+        pub fn new() -> DraftPost {
+            DraftPost {
+                content: String::new(),
+            }
+        }
+
+        // only Post has content()
+        pub fn content(&self) -> &str {
+            &self.content
+        }
+    }
+
+    impl DraftPost {
+        pub fn add_text(&mut self, text: &str) {
+            self.content.push_str(text);
+        }
+
+        // self by value: the draft is gone afterwards
+        pub fn request_review(self) -> PendingReviewPost {
+            PendingReviewPost {
+                content: self.content,
+            }
+        }
+    }
+
+    impl PendingReviewPost {
+        pub fn approve(self) -> Post {
+            Post {
+                content: self.content,
+            }
+        }
+
+        pub fn reject(self) -> DraftPost {
+            DraftPost {
+                content: self.content,
+            }
+        }
+    }
+}
+
+fn typestate() {
+    let mut post = typed_post::Post::new();
+    post.add_text("I ate a salad for lunch today");
+    // post.approve(); // no method `approve` on DraftPost (compile-fail typestate_skip_review)
+    // post.content(); // no `content` either, a draft shows nothing (typestate_draft_content)
+    let post = post.request_review(); // shadowing, post is a PendingReviewPost now
+    let post = post.approve(); // and a Post
+    println!("{}", post.content()); // I ate a salad for lunch today
+}
+
+// Both implementations against the same rules, step by step.
+// The state pattern checks them at runtime and ignores the wrong steps,
+// the typestate version doesn't compile with them, so they are comments there.
+fn same_rules() {
+    // only drafts can be edited
+    let mut post = state_post::Post::new();
+    post.add_text("salad");
+    post.request_review();
+    post.add_text(" and cake"); // ignored
+    println!("{}", post.status()); // pending review
+    let mut draft = typed_post::Post::new();
+    draft.add_text("salad");
+    let pending = draft.request_review();
+    // pending.add_text(" and cake"); // no `add_text` on PendingReviewPost (typestate_edit_pending)
+
+    // a draft has to be reviewed before it is published
+    let mut skipped = state_post::Post::new();
+    skipped.approve(); // ignored
+    println!("{}", skipped.status()); // draft
+
+    // typed_post::Post::new().approve(); // no `approve` on DraftPost (typestate_skip_review)
+
+    // rejecting sends it back to draft, where it can be edited again
+    post.reject();
+    post.add_text(" for lunch");
+    println!("{} {:?}", post.status(), post.content()); // draft "", a draft shows nothing
+    let mut draft = pending.reject();
+    draft.add_text(" for lunch");
+
+    // approving publishes, only then the text is shown
+    post.request_review();
+    post.approve();
+    println!("{}: {}", post.status(), post.content()); // published: salad for lunch
+    let published = draft.request_review().approve();
+    println!("published: {}", published.content()); // published: salad for lunch
+
+    // the typestate post can't go back to draft or review, the state pattern one ignores it
+    post.reject();
+    println!("{}", post.status()); // published
+
+    // published.reject(); // no method `reject` on Post (typestate_reject_published)
+}
+
+// the typestate post breaks these rules only in code that doesn't compile, one snippet of
+// `localplayground compile-fail` per rule: typestate_edit_pending, typestate_skip_review,
+// typestate_draft_content and typestate_reject_published
+#[cfg(test)]
+mod tests {
+    use super::{state_post, typed_post};
+
+    #[test]
+    fn only_drafts_can_be_edited() {
+        let mut post = state_post::Post::new();
+        post.add_text("salad");
+        post.request_review();
+        post.add_text(" and cake");
+        post.approve();
+        post.add_text(" and cake");
+        assert_eq!(post.content(), "salad");
+    }
+
+    #[test]
+    fn no_approval_before_review() {
+        let mut post = state_post::Post::new();
+        post.add_text("salad");
+        post.approve();
+        assert_eq!(post.status(), "draft");
+        post.request_review();
+        assert_eq!(post.status(), "pending review");
+        post.approve();
+        assert_eq!(post.status(), "published");
+    }
+
+    #[test]
+    fn reject_goes_back_to_draft() {
+        let mut post = state_post::Post::new();
+        post.add_text("salad");
+        post.request_review();
+        post.reject();
+        assert_eq!(post.status(), "draft");
+        post.add_text(" for lunch");
+        post.request_review();
+        post.approve();
+        assert_eq!(post.content(), "salad for lunch");
+
+        let mut draft = typed_post::Post::new();
+        draft.add_text("salad");
+        let mut draft = draft.request_review().reject();
+        draft.add_text(" for lunch");
+        assert_eq!(
+            draft.request_review().approve().content(),
+            "salad for lunch"
+        );
+    }
+
+    #[test]
+    fn content_only_when_published() {
+        let mut post = state_post::Post::new();
+        post.add_text("salad");
+        assert_eq!(post.content(), "");
+        post.request_review();
+        assert_eq!(post.content(), "");
+        post.approve();
+        assert_eq!(post.content(), "salad");
+        // a published post stays published
+        post.reject();
+        post.request_review();
+        assert_eq!((post.status(), post.content()), ("published", "salad"));
+    }
+}
@@ -0,0 +1,752 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+use std::rc::Rc;
+use std::str::FromStr;
+
+use super::portfolio::{Account, Broker, Limit, Money, Order, OrderError, Side};
+use super::{Demo, Lesson};
+
+pub const LESSON: Lesson = Lesson {
+    name: "order_book",
+    source: include_str!("order_book.rs"),
+    requires: &["portfolio", "closures", "smart_pointers"],
+    demos: &[
+        Demo {
+            name: "matching",
+            run: matching,
+        },
+        Demo {
+            name: "cancel_replace",
+            run: cancel_replace,
+        },
+        Demo {
+            name: "routing",
+            run: routing,
+        },
+        Demo {
+            name: "replaying",
+            run: replaying,
+        },
+    ],
+};
+
+/*
+Project: an order book
+*/
+// in portfolio every order was filled at the price of the feed, as if nobody else traded
+// on an exchange a buyer always trades with a seller. Orders that can't be filled yet wait
+// in an order book, one for each instrument, and every new order is matched against them:
+//  - price-time priority: the best price first, on the same price the older order first
+//  - the trade happens at the price of the waiting order, the new one gets that or better
+//  - partial fills: an order takes what's there, a limit order waits with the rest,
+//    a market order drops it
+// nothing depends on the clock or on the order of a HashMap, so the same orders always
+// give the same trades and a recorded session can be replayed and compared
+
+pub type OrderId = u64;
+
+//      Trades and events
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trade {
+    pub symbol: String,
+    pub quantity: u32,
+    pub price: Money,
+    pub buyer: String,
+    pub seller: String,
+    // the order that was waiting, and the new one that matched it
+    pub resting: OrderId,
+    pub incoming: OrderId,
+}
+
+impl fmt::Display for Trade {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} at {}, {} to {} (#{} took #{})",
+            self.quantity,
+            self.symbol,
+            self.price,
+            self.seller,
+            self.buyer,
+            self.incoming,
+            self.resting
+        )
+    }
+}
+
+// everything that happens on the exchange, in the order it happened
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    Accepted {
+        id: OrderId,
+        owner: String,
+        order: Order,
+    },
+    Traded(Trade),
+    Cancelled {
+        id: OrderId,
+        remaining: u32,
+        reason: String,
+    },
+    Replaced {
+        id: OrderId,
+        quantity: u32,
+        price: Money,
+        kept_priority: bool,
+    },
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Event::Accepted { id, owner, order } => {
+                let side = match order.side {
+                    Side::Buy => "buys",
+                    Side::Sell => "sells",
+                };
+                write!(
+                    f,
+                    "#{id} {owner} {side} {} {}",
+                    order.quantity, order.symbol
+                )?;
+                match order.limit {
+                    Limit::Market => write!(f, " at market"),
+                    Limit::At(price) => write!(f, " at {price}"),
+                }
+            }
+            Event::Traded(trade) => write!(f, "trade: {trade}"),
+            Event::Cancelled {
+                id,
+                remaining,
+                reason,
+            } => write!(f, "#{id} cancelled with {remaining} left: {reason}"),
+            Event::Replaced {
+                id,
+                quantity,
+                price,
+                kept_priority,
+            } => {
+                let place = if *kept_priority { "keeps" } else { "loses" };
+                write!(f, "#{id} is now {quantity} at {price}, {place} its place")
+            }
+        }
+    }
+}
+
+//      The book
+pub struct Resting {
+    pub id: OrderId,
+    pub owner: String,
+    pub quantity: u32,
+}
+
+// a trade can be refused by settlement, for the buyer or the seller
+pub type Refusal = (Side, OrderError);
+
+// the waiting orders of one instrument
+pub struct OrderBook {
+    symbol: String,
+    // BTreeMap keeps the prices sorted, the best bid is the last key, the best ask the first
+    // VecDeque: the oldest order of a price is at the front
+    bids: BTreeMap<Money, VecDeque<Resting>>,
+    asks: BTreeMap<Money, VecDeque<Resting>>,
+    // where every waiting order is, for cancel and replace
+    index: BTreeMap<OrderId, (Side, Money)>,
+}
+
+fn opposite(side: Side) -> Side {
+    match side {
+        Side::Buy => Side::Sell,
+        Side::Sell => Side::Buy,
+    }
+}
+
+impl OrderBook {
+    pub fn new(symbol: &str) -> OrderBook {
+        OrderBook {
+            symbol: symbol.to_string(),
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+            index: BTreeMap::new(),
+        }
+    }
+
+    fn levels(&mut self, side: Side) -> &mut BTreeMap<Money, VecDeque<Resting>> {
+        match side {
+            Side::Buy => &mut self.bids,
+            Side::Sell => &mut self.asks,
+        }
+    }
+
+    fn best(&self, side: Side) -> Option<Money> {
+        match side {
+            Side::Buy => self.bids.keys().next_back().copied(),
+            Side::Sell => self.asks.keys().next().copied(),
+        }
+    }
+
+    // every price with the quantity waiting there, the best first
+    pub fn depth(&self, side: Side) -> Vec<(Money, u32)> {
+        let total = |(price, level): (&Money, &VecDeque<Resting>)| {
+            (*price, level.iter().map(|resting| resting.quantity).sum())
+        };
+        match side {
+            Side::Buy => self.bids.iter().rev().map(total).collect(),
+            Side::Sell => self.asks.iter().map(total).collect(),
+        }
+    }
+
+    pub fn find(&self, id: OrderId) -> Option<(Side, Money, &Resting)> {
+        let &(side, price) = self.index.get(&id)?;
+        let levels = match side {
+            Side::Buy => &self.bids,
+            Side::Sell => &self.asks,
+        };
+        let resting = levels[&price].iter().find(|resting| resting.id == id)?;
+        Some((side, price, resting))
+    }
+
+    // matches the order against the other side for as long as the prices fit,
+    // settle is asked before every trade and may refuse it
+    pub fn submit(
+        &mut self,
+        id: OrderId,
+        owner: &str,
+        order: &Order,
+        settle: &mut impl FnMut(&Trade) -> Result<(), Refusal>,
+        events: &mut Vec<Event>,
+    ) {
+        let against = opposite(order.side);
+        let mut remaining = order.quantity;
+        while remaining > 0 {
+            let Some(price) = self.best(against).filter(|&price| order.accepts(price)) else {
+                break;
+            };
+            // best found the level, and empty levels are removed right away
+            let level = self.levels(against).get_mut(&price).unwrap();
+            let resting = level.front_mut().unwrap();
+            let quantity = remaining.min(resting.quantity);
+            let (buyer, seller) = match order.side {
+                Side::Buy => (owner, resting.owner.as_str()),
+                Side::Sell => (resting.owner.as_str(), owner),
+            };
+            let trade = Trade {
+                symbol: order.symbol.clone(),
+                quantity,
+                price,
+                buyer: buyer.to_string(),
+                seller: seller.to_string(),
+                resting: resting.id,
+                incoming: id,
+            };
+            match settle(&trade) {
+                Ok(()) => {
+                    remaining -= quantity;
+                    resting.quantity -= quantity;
+                    events.push(Event::Traded(trade));
+                }
+                // the new order can't pay, it's done
+                Err((side, reason)) if side == order.side => {
+                    events.push(Event::Cancelled {
+                        id,
+                        remaining,
+                        reason: reason.to_string(),
+                    });
+                    return;
+                }
+                // the waiting one can't, it leaves the book and the next one gets a chance
+                Err((_, reason)) => {
+                    events.push(Event::Cancelled {
+                        id: resting.id,
+                        remaining: resting.quantity,
+                        reason: reason.to_string(),
+                    });
+                    resting.quantity = 0;
+                }
+            }
+            if resting.quantity == 0 {
+                let done = level.pop_front().unwrap();
+                if level.is_empty() {
+                    self.levels(against).remove(&price);
+                }
+                self.index.remove(&done.id);
+            }
+        }
+        if remaining == 0 {
+            return;
+        }
+        match order.limit {
+            Limit::Market => events.push(Event::Cancelled {
+                id,
+                remaining,
+                reason: String::from("nothing left to trade with"),
+            }),
+            Limit::At(price) => {
+                let resting = Resting {
+                    id,
+                    owner: owner.to_string(),
+                    quantity: remaining,
+                };
+                self.levels(order.side)
+                    .entry(price)
+                    .or_default()
+                    .push_back(resting);
+                self.index.insert(id, (order.side, price));
+            }
+        }
+    }
+
+    pub fn cancel(&mut self, id: OrderId) -> Option<Resting> {
+        let (side, price) = self.index.remove(&id)?;
+        let level = self.levels(side).get_mut(&price)?;
+        let position = level.iter().position(|resting| resting.id == id)?;
+        let resting = level.remove(position);
+        if level.is_empty() {
+            self.levels(side).remove(&price);
+        }
+        resting
+    }
+
+    // less of the same keeps the place in the queue, see Exchange::replace
+    fn reduce(&mut self, id: OrderId, quantity: u32) {
+        let Some(&(side, price)) = self.index.get(&id) else {
+            return;
+        };
+        let level = self.levels(side).get_mut(&price);
+        if let Some(resting) = level.and_then(|level| level.iter_mut().find(|r| r.id == id)) {
+            resting.quantity = quantity;
+        }
+    }
+}
+
+//      The exchange
+// one book per symbol, order ids, and settlement with the accounts of its members
+// traders without an account are "the market", their trades always settle
+// members are checked at every trade instead of when they place the order: a limit order
+// may wait for days, the cash can be gone by then
+#[derive(Debug, PartialEq)]
+pub enum ExchangeError {
+    Order(OrderError),
+    UnknownOrder(OrderId),
+}
+
+impl From<OrderError> for ExchangeError {
+    fn from(error: OrderError) -> ExchangeError {
+        ExchangeError::Order(error)
+    }
+}
+
+impl fmt::Display for ExchangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExchangeError::Order(error) => write!(f, "{error}"),
+            ExchangeError::UnknownOrder(id) => write!(f, "there is no waiting order #{id}"),
+        }
+    }
+}
+
+impl std::error::Error for ExchangeError {}
+
+// a closure that gets every event, FnMut so it may change what it captured
+// (closures::example_closure)
+pub type Subscriber = Box<dyn FnMut(&Event)>;
+
+pub struct Exchange {
+    date: String,
+    next_id: OrderId,
+    books: BTreeMap<String, OrderBook>,
+    members: BTreeMap<String, Account>,
+    subscribers: Vec<Subscriber>,
+}
+
+// both accounts are checked before either one changes, so a refused trade changes nothing
+fn settle(
+    members: &mut BTreeMap<String, Account>,
+    date: &str,
+    trade: &Trade,
+) -> Result<(), Refusal> {
+    let sides = [(Side::Buy, &trade.buyer), (Side::Sell, &trade.seller)];
+    for (side, name) in sides {
+        if let Some(account) = members.get(name) {
+            account
+                .check(side, &trade.symbol, trade.quantity, trade.price)
+                .map_err(|e| (side, e))?;
+        }
+    }
+    for (side, name) in sides {
+        if let Some(account) = members.get_mut(name) {
+            account
+                .trade(date, side, &trade.symbol, trade.quantity, trade.price)
+                .map_err(|e| (side, e))?;
+        }
+    }
+    Ok(())
+}
+
+impl Exchange {
+    pub fn new(date: &str) -> Exchange {
+        Exchange {
+            date: date.to_string(),
+            next_id: 1,
+            books: BTreeMap::new(),
+            members: BTreeMap::new(),
+            subscribers: Vec::new(),
+        }
+    }
+
+    pub fn join(&mut self, name: &str, account: Account) {
+        self.members.insert(name.to_string(), account);
+    }
+
+    pub fn account(&self, name: &str) -> Option<&Account> {
+        self.members.get(name)
+    }
+
+    pub fn book(&self, symbol: &str) -> Option<&OrderBook> {
+        self.books.get(symbol)
+    }
+
+    pub fn subscribe(&mut self, subscriber: impl FnMut(&Event) + 'static) {
+        self.subscribers.push(Box::new(subscriber));
+    }
+
+    fn publish(&mut self, events: Vec<Event>) {
+        for event in &events {
+            for subscriber in &mut self.subscribers {
+                subscriber(event);
+            }
+        }
+    }
+
+    pub fn submit(&mut self, owner: &str, order: Order) -> Result<OrderId, ExchangeError> {
+        order.validate()?;
+        let id = self.next_id;
+        self.next_id += 1;
+        let mut events = vec![Event::Accepted {
+            id,
+            owner: owner.to_string(),
+            order: order.clone(),
+        }];
+        let book = self
+            .books
+            .entry(order.symbol.clone())
+            .or_insert_with(|| OrderBook::new(&order.symbol));
+        // two different fields of self, the borrow checker allows both at once
+        let (members, date) = (&mut self.members, &self.date);
+        let mut settle = |trade: &Trade| settle(members, date, trade);
+        book.submit(id, owner, &order, &mut settle, &mut events);
+        self.publish(events);
+        Ok(id)
+    }
+
+    pub fn cancel(&mut self, id: OrderId) -> Result<(), ExchangeError> {
+        let resting = self
+            .books
+            .values_mut()
+            .find_map(|book| book.cancel(id))
+            .ok_or(ExchangeError::UnknownOrder(id))?;
+        self.publish(vec![Event::Cancelled {
+            id,
+            remaining: resting.quantity,
+            reason: String::from("cancelled by its owner"),
+        }]);
+        Ok(())
+    }
+
+    // less at the same price keeps the order's place in the queue,
+    // anything else is a new order with the same id that goes to the back (and may trade)
+    pub fn replace(
+        &mut self,
+        id: OrderId,
+        quantity: u32,
+        price: Money,
+    ) -> Result<(), ExchangeError> {
+        if quantity == 0 {
+            return Err(OrderError::NoQuantity.into());
+        }
+        if price <= Money(0) {
+            return Err(OrderError::NoLimit(price).into());
+        }
+        let book = self
+            .books
+            .values_mut()
+            .find(|book| book.index.contains_key(&id))
+            .ok_or(ExchangeError::UnknownOrder(id))?;
+        let (side, old_price, resting) = book.find(id).unwrap(); // it's in the index
+        let kept_priority = price == old_price && quantity <= resting.quantity;
+        let mut events = vec![Event::Replaced {
+            id,
+            quantity,
+            price,
+            kept_priority,
+        }];
+        if kept_priority {
+            book.reduce(id, quantity);
+        } else {
+            let resting = book.cancel(id).unwrap();
+            let order = Order {
+                side,
+                limit: Limit::At(price),
+                ..Order::buy(&book.symbol, quantity)
+            };
+            let (members, date) = (&mut self.members, &self.date);
+            let mut settle = |trade: &Trade| settle(members, date, trade);
+            book.submit(id, &resting.owner, &order, &mut settle, &mut events);
+        }
+        self.publish(events);
+        Ok(())
+    }
+
+    // the buy_in and sell_out of portfolio, through the book instead of the price feed
+    pub fn buy_in(
+        &mut self,
+        member: &str,
+        symbol: &str,
+        quantity: u32,
+    ) -> Result<OrderId, ExchangeError> {
+        self.submit(member, Order::buy(symbol, quantity))
+    }
+
+    pub fn sell_out(&mut self, member: &str, symbol: &str) -> Result<OrderId, ExchangeError> {
+        let held = self
+            .account(member)
+            .and_then(|account| account.position(symbol))
+            .map_or(0, |position| position.quantity);
+        self.submit(member, Order::sell(symbol, held))
+    }
+}
+
+fn matching() {
+    let mut exchange = Exchange::new("2024-03-07");
+    exchange.subscribe(|event| {
+        if let Event::Traded(trade) = event {
+            println!("{trade}");
+        }
+    });
+    let ask = |quantity, cents| Order::sell("FERRIS", quantity).at(Money(cents));
+    exchange.submit("ann", ask(10, 10_100)).unwrap(); // #1
+    exchange.submit("ben", ask(10, 10_100)).unwrap(); // #2, same price but later
+    exchange.submit("cid", ask(5, 10_050)).unwrap(); // #3, cheaper
+
+    // the cheapest first, then the older of the two at 101.00
+    exchange.buy_in("dora", "FERRIS", 12).unwrap();
+    // "5 FERRIS at 100.50, cid to dora (#4 took #3)"
+    // "7 FERRIS at 101.00, ann to dora (#4 took #1)"
+    let book = exchange.book("FERRIS").unwrap();
+    println!("{:?}", book.depth(Side::Sell)); // "[(Money(10100), 13)]"
+
+    // a limit order takes what fits and waits with the rest
+    let bid = Order::buy("FERRIS", 20).at(Money(10_100));
+    exchange.submit("eve", bid).unwrap();
+    // "3 FERRIS at 101.00, ann to eve (#5 took #1)"
+    // "10 FERRIS at 101.00, ben to eve (#5 took #2)"
+    let book = exchange.book("FERRIS").unwrap();
+    let (bids, asks) = (book.depth(Side::Buy), book.depth(Side::Sell));
+    println!("{bids:?} {asks:?}"); // "[(Money(10100), 7)] []"
+}
+
+fn cancel_replace() {
+    let mut exchange = Exchange::new("2024-03-07");
+    exchange.subscribe(|event| println!("{event}"));
+    let bid = |quantity, cents| Order::buy("CRAB", quantity).at(Money(cents));
+    exchange.submit("ann", bid(10, 2_000)).unwrap(); // #1 ann buys 10 CRAB at 20.00
+    exchange.submit("ben", bid(10, 2_000)).unwrap(); // #2 ben buys 10 CRAB at 20.00
+
+    // less at the same price: ann stays in front of ben
+    exchange.replace(1, 6, Money(2_000)).unwrap(); // "#1 is now 6 at 20.00, keeps its place"
+
+    // more: ben goes behind everything on 20.00, here that changes nothing
+    exchange.replace(2, 12, Money(2_000)).unwrap(); // "#2 is now 12 at 20.00, loses its place"
+
+    // "#3 cid sells 8 CRAB at market"
+    // "trade: 6 CRAB at 20.00, cid to ann (#3 took #1)"
+    // "trade: 2 CRAB at 20.00, cid to ben (#3 took #2)"
+    exchange.submit("cid", Order::sell("CRAB", 8)).unwrap();
+
+    exchange.cancel(2).unwrap(); // #2 cancelled with 10 left: cancelled by its owner
+    println!("{}", exchange.cancel(2).unwrap_err()); // there is no waiting order #2
+    let book = exchange.book("CRAB").unwrap();
+    println!("{}", book.depth(Side::Buy).is_empty()); // true
+}
+
+fn routing() {
+    let mut exchange = Exchange::new("2024-03-07");
+    exchange.subscribe(|event| {
+        if let Event::Cancelled { .. } = event {
+            println!("{event}");
+        }
+    });
+    let broker = Broker::Neobroker {
+        name: String::from("Trade Republic"),
+    };
+    exchange.join("alice", Account::new(broker, Money(100_000)));
+    // the market maker has no account, it's the rest of the market
+    let ask = |quantity, cents| Order::sell("FERRIS", quantity).at(Money(cents));
+    exchange.submit("maker", ask(3, 10_000)).unwrap();
+    exchange.submit("maker", ask(3, 10_100)).unwrap();
+    let bid = Order::buy("FERRIS", 4).at(Money(9_900));
+    exchange.submit("maker", bid).unwrap();
+
+    exchange.buy_in("alice", "FERRIS", 5).unwrap(); // 3 at 100.00 and 2 at 101.00
+    let alice = exchange.account("alice").unwrap();
+    let (ferris, cash) = (alice.position("FERRIS").unwrap(), alice.cash());
+    println!("{} for {}, {cash} left", ferris.quantity, ferris.cost); // "5 for 504.00, 496.00 left"
+
+    // there are only 4 bids, the rest of a market order is dropped
+    // "#5 cancelled with 1 left: nothing left to trade with"
+    exchange.sell_out("alice", "FERRIS").unwrap();
+    let alice = exchange.account("alice").unwrap();
+    let (ferris, cash) = (alice.position("FERRIS").unwrap(), alice.cash());
+    println!("{} {cash}", ferris.quantity); // 1 891.00
+
+    // alice wants more than she can pay, the book doesn't know until a trade is settled
+    let too_much = Order::buy("FERRIS", 10).at(Money(10_000));
+    exchange.submit("alice", too_much).unwrap();
+    // "#6 cancelled with 10 left: needs 1001.00 but only 891.00 is available"
+    exchange.submit("maker", ask(10, 10_000)).unwrap();
+    let book = exchange.book("FERRIS").unwrap();
+    println!("{:?}", book.depth(Side::Sell)); // "[(Money(10000), 10), (Money(10100), 1)]"
+
+    for transaction in exchange.account("alice").unwrap().ledger() {
+        println!("{transaction}");
+    }
+}
+
+//      Replaying a session
+// the orders of data/session.txt, one per line. An exchange is deterministic,
+// so replaying them always gives the same events, and the trades among them are compared
+// with the ones recorded in data/session_trades.txt
+const SESSION: &str = include_str!("data/session.txt");
+const SESSION_TRADES: &str = include_str!("data/session_trades.txt");
+
+// one trade per line, lines starting with # are comments
+fn recorded_trades(text: &str) -> Vec<&str> {
+    text.lines()
+        .filter(|line| !line.starts_with('#') && !line.trim().is_empty())
+        .collect()
+}
+
+pub fn trades(events: &[Event]) -> Vec<String> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            Event::Traded(trade) => Some(trade.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn number<T: FromStr>(word: &str) -> Result<T, String> {
+    word.parse()
+        .map_err(|_| format!("`{word}` is not a number"))
+}
+
+fn price(word: &str) -> Result<Money, String> {
+    Money::parse(word).ok_or_else(|| format!("`{word}` is not a price"))
+}
+
+fn play(exchange: &mut Exchange, line: &str) -> Result<(), String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let done = match words[..] {
+        ["cancel", id] => exchange.cancel(number(id)?),
+        ["replace", id, quantity, limit] => {
+            exchange.replace(number(id)?, number(quantity)?, price(limit)?)
+        }
+        [trader, side, quantity, symbol, ref limit @ ..] if limit.len() <= 1 => {
+            let order = match side {
+                "buy" => Order::buy(symbol, number(quantity)?),
+                "sell" => Order::sell(symbol, number(quantity)?),
+                _ => return Err(format!("`{side}` is neither buy nor sell")),
+            };
+            let order = match limit {
+                [limit] => order.at(price(limit)?),
+                _ => order,
+            };
+            exchange.submit(trader, order).map(|_| ())
+        }
+        _ => return Err(format!("can't read `{line}`")),
+    };
+    done.map_err(|e| e.to_string())
+}
+
+pub fn replay(session: &str) -> Result<Vec<Event>, String> {
+    let mut exchange = Exchange::new("2024-03-07");
+    // the subscriber and this function both need the events, see smart_pointers
+    let recorded = Rc::new(RefCell::new(Vec::new()));
+    let recorder = Rc::clone(&recorded);
+    exchange.subscribe(move |event| recorder.borrow_mut().push(event.clone()));
+    for (index, line) in session.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if !line.is_empty() {
+            play(&mut exchange, line).map_err(|e| format!("line {}: {e}", index + 1))?;
+        }
+    }
+    Ok(recorded.take())
+}
+
+fn replaying() {
+    let events = replay(SESSION).unwrap();
+    println!("{} events", events.len()); // 17 events
+    let trades = trades(&events);
+    println!("{}", trades[0]); // "10 FERRIS at 101.00, maker to alice (#5 took #1)"
+    println!("{}", trades[5]); // "3 FERRIS at 100.00, erin to dave (#9 took #8)"
+    let recorded = recorded_trades(SESSION_TRADES);
+    println!("as recorded: {}", trades == recorded); // as recorded: true
+
+    let broken = replay("ann buy 5 FERRIS\nann hold 5 FERRIS");
+    println!("{}", broken.unwrap_err()); // line 2: `hold` is neither buy nor sell
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{recorded_trades, replay, trades, SESSION, SESSION_TRADES};
+    use super::{Exchange, ExchangeError, Money, Order, OrderError};
+
+    fn no_limit(limit: i64) -> ExchangeError {
+        ExchangeError::Order(OrderError::NoLimit(Money(limit)))
+    }
+
+    #[test]
+    fn session_gives_the_recorded_trades() {
+        let events = replay(SESSION).unwrap();
+        assert_eq!(trades(&events), recorded_trades(SESSION_TRADES));
+    }
+
+    #[test]
+    fn submit_refuses_limits_no_price_reaches() {
+        let mut exchange = Exchange::new("2024-03-07");
+        for limit in [0, -100] {
+            let order = Order::sell("FERRIS", 5).at(Money(limit));
+            assert_eq!(exchange.submit("ann", order), Err(no_limit(limit)));
+        }
+        assert!(exchange
+            .submit("ann", Order::sell("FERRIS", 5).at(Money(1)))
+            .is_ok());
+    }
+
+    #[test]
+    fn replace_refuses_limits_no_price_reaches() {
+        let mut exchange = Exchange::new("2024-03-07");
+        let order = Order::buy("FERRIS", 5).at(Money(10_000));
+        let id = exchange.submit("ann", order).unwrap();
+        assert_eq!(exchange.replace(id, 5, Money(0)), Err(no_limit(0)));
+        assert_eq!(exchange.replace(id, 5, Money(-1)), Err(no_limit(-1)));
+        // the order is still there, unchanged
+        assert_eq!(exchange.replace(id, 4, Money(10_000)), Ok(()));
+    }
+
+    #[test]
+    fn replay_refuses_limits_no_price_reaches() {
+        let refused = replay("ann buy 5 FERRIS 101.00\nbob sell 5 FERRIS -1.00");
+        assert_eq!(
+            refused.unwrap_err(),
+            "line 2: a limit of -1.00 is not a price"
+        );
+        let refused = replay("ann buy 5 FERRIS 101.00\nreplace 1 5 0");
+        assert_eq!(
+            refused.unwrap_err(),
+            "line 2: a limit of 0.00 is not a price"
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use super::lifetimes::{as_string, increment, new_counter};
+use super::{Demo, Lesson};
+
+pub const LESSON: Lesson = Lesson {
+    name: "ownership",
+    source: include_str!("ownership.rs"),
+    requires: &["data_types"],
+    demos: &[
+        Demo {
+            name: "moving",
+            run: moving,
+        },
+        Demo {
+            name: "borrowing",
+            run: borrowing,
+        },
+        Demo {
+            name: "mutable_borrowing",
+            run: mutable_borrowing,
+        },
+        Demo {
+            name: "overlapping_borrowing",
+            run: overlapping_borrowing,
+        },
+        Demo {
+            name: "copy_trait",
+            run: copy_trait,
+        },
+        Demo {
+            name: "clone_trait",
+            run: clone_trait,
+        },
+    ],
+};
+
+/*
+    Ownership und Borrowing
+*/
+// Ownership is saved on the stack
+//       Normal Borrowing
+
+fn takes_ownership(text: String) {
+    println!("{}", text);
+}
+
+fn moving() {
+    let text: String = String::from("jeremias");
+    takes_ownership(text); // text is moved into the function and invalidated here
+}
+// using text afterwards does not compile, see compile-fail not_possible and moved_after_borrow
+
+// This will work
+fn borrows(text: &String) {
+    println!("{}", text);
+}
+
+fn borrowing() {
+    let text: String = String::from("jeremias");
+    borrows(&text);
+    println!("{}", text); // jeremias, text is still the owner
+}
+
+//      Mutable Borrowing
+// allows only one borrow at a time (compile-fail double_mut_borrowing)
+
+// this works
+fn borrows_mutable(text: &mut String) {
+    println!("{}", text); // jeremias
+    *text = format!("{}{}", text, " 13")
+}
+
+fn mutable_borrowing() {
+    let mut text = String::from("jeremias");
+    borrows_mutable(&mut text); // this works
+
+    println!("{}", text); // jeremias 13
+}
+
+// the shared borrows end with their last use, after that we may borrow mutably again
+// incrementing in between does not compile, see compile-fail overlapping_mut_borrowing
+fn overlapping_borrowing() {
+    let cnt = new_counter("my counter".to_string());
+    increment(cnt);
+    increment(cnt);
+    let cnt_ref1 = &(*cnt);
+    let cnt_ref2 = &(*cnt);
+    println!("{}", as_string(cnt_ref1));
+    // last use of cnt_ref2
+    println!("{}", as_string(cnt_ref2));
+    increment(cnt); // works, because no shared borrow is alive anymore
+    println!("{}", as_string(cnt));
+}
+
+//      Copy and Clone Trait
+
+// Copy:
+// we can use this traits to automatically create a new owner to the data
+// owner holds data
+// data gets copied
+// owner1 and owner2 hold same memory address
+// u8 implements Copy. Copy can be implemented on own data structures
+fn copy_trait() {
+    let x: u8 = 123;
+    let y = x;
+    // x can still be used
+    println!("x={}, y={}", x, y);
+}
+
+// Structs are moved because they don't have the Copy Trait, see compile-fail copy_fails
+// this would work if RandomStruct had Copy Trait, as random_func would get a as a copy
+// Beware of Dangling Pointers! If a was to not live longer than the functions, then:
+// owner dropped -> reference to data dropped -> data droppped (even though random_func() uses it)
+
+// Clone
+// once cloned, we have completely new owners with their own data, in no way depend on each other
+fn use_v(v: Vec<u8>) {
+    println!("{:?}", v);
+}
+
+fn clone_trait() {
+    // Vec<u8> implements Clone, but not Copy
+    let v: Vec<u8> = vec![1, 2, 3];
+    let w = v.clone();
+    use_v(v); // possible
+    use_v(w);
+    // using
+    // let w = v
+    // would *move* the value, rendering v unusable.
+    // println!("{}", v) would fail
+}
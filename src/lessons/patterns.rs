@@ -0,0 +1,156 @@
+use super::{Demo, Lesson};
+
+pub const LESSON: Lesson = Lesson {
+    name: "patterns",
+    source: include_str!("patterns.rs"),
+    requires: &["ownership", "control_flow", "structs_enums"],
+    demos: &[
+        Demo {
+            name: "pattern_matching",
+            run: pattern_matching,
+        },
+        Demo {
+            name: "decomposing",
+            run: decomposing,
+        },
+        Demo {
+            name: "refutability",
+            run: refutability,
+        },
+    ],
+};
+
+/*
+Pattern Matching
+*/
+
+fn pattern_matching() {
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+    let p = Point { x: 0, y: 7 };
+    // matching the structure of Point with variables to an instance
+    let Point { x: a, y: b } = p; // from now on, a and b are accessible as the values of point p
+    println!("a = {a}, b = {b}");
+
+    // we've mostly handled this already, but there are some edge cases
+    let x = Some(5);
+    let y = 10;
+
+    match x {
+        Some(50) => println!("Got 50"),
+        Some(y) => println!("Matched, y = {y}"), // This Statement shadows the outer "y", it will not use y as value 10
+        _ => println!("Default case, x = {:?}", x),
+    }
+
+    // But this will print x= 5 and y = 10, as the scope of y (in match statement) ended
+    println!("at the end: x = {:?}, y = {y}", x);
+}
+
+// Ownership and Borrowing was already explained, see above
+// Guards, Ranges and Bindings were also explained above
+
+//      Decompose Types ???
+// when will data be dropped?
+// Broker prints its name when it is dropped, so we can watch the order
+#[derive(PartialEq)]
+enum Broker {
+    Neobroker(&'static str),
+}
+
+impl Drop for Broker {
+    fn drop(&mut self) {
+        let Broker::Neobroker(name) = self;
+        println!("drop {name}");
+    }
+}
+
+// the loop only exists to break out of the tuple, clippy doesn't like that at all
+#[allow(
+    unreachable_code,
+    clippy::never_loop,
+    clippy::unnecessary_operation,
+    clippy::diverging_sub_expression
+)]
+fn decomposing() {
+    // Neobroker Dropped once the condition has been evaluated
+    let a = Broker::Neobroker("If condition");
+    if Broker::Neobroker("If condition") == a {
+        // btw for == you would need to implement PartialEq
+        // Neobroker Dropped at the end of the block
+        Broker::Neobroker("If body");
+    }
+    match Broker::Neobroker("Dropped at end of match") {
+        Broker::Neobroker(b) if b.starts_with("Nothing") => (), // drop
+        _ => (),                                                // drop
+    }
+
+    loop {
+        // Tuple expression doesn't finish evaluating so operands drop in reverse order
+        (
+            Broker::Neobroker("Outer tuple first"),
+            Broker::Neobroker("Outer tuple second"),
+            (
+                Broker::Neobroker("Inner tuple first"),
+                Broker::Neobroker("Inner tuple second"),
+                break, // now drop in reverse
+            ),
+            Broker::Neobroker("Never created"),
+        );
+    }
+
+    {
+        // SPECIAL case
+        let x = &mut 0;
+        // Usually a temporary would be dropped by now, but the temporary for `0` lives
+        // to the end of the block.
+        println!("{}", x);
+    }
+
+    // The temporary that stores the result of `temp()` only lives until the
+    // end of the let statement, let x = temp().use_temp(); and using x afterwards
+    // doesn't compile (compile-fail temporary_dropped)
+
+    // a is dropped last, at the end of the function
+}
+
+//      Definition Refutability
+// let Some(x) = some_value; gives an error, as the Patterns don't match (compile-fail refutability)
+// let (x,y) = (1,2,3); also doesn't match (compile-fail refutability_tuple)
+fn refutability() {
+    let some_value: Option<i32> = None;
+
+    // handle these Problems
+    if let Some(x) = some_value {
+        // if patterns match, print the value, else, do nothing
+        println!("{}", x);
+    }
+
+    // example of how to handle possibly unmatching types
+    #[allow(dead_code)]
+    enum Color {
+        Rgb(i32, i32, i32),
+        Hsv(i32, i32, i32),
+    }
+
+    #[allow(dead_code)]
+    enum Message {
+        Quit,
+        Move { x: i32, y: i32 },
+        Write(String),
+        ChangeColor(Color),
+    }
+
+    let msg = Message::ChangeColor(Color::Hsv(0, 160, 255));
+
+    match msg {
+        Message::ChangeColor(Color::Rgb(r, g, b)) => {
+            println!("Change color to red {r}, green {g}, and blue {b}");
+        }
+        Message::ChangeColor(Color::Hsv(h, s, v)) => {
+            println!("Change color to hue {h}, saturation {s}, value {v}")
+        }
+        _ => (),
+    }
+}
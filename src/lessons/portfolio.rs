@@ -0,0 +1,797 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::ops::{Add, Sub};
+
+use super::{Demo, Lesson};
+
+pub const LESSON: Lesson = Lesson {
+    name: "portfolio",
+    source: include_str!("portfolio.rs"),
+    requires: &["structs_enums", "collections", "errors", "traits"],
+    demos: &[
+        Demo {
+            name: "brokers",
+            run: brokers,
+        },
+        Demo {
+            name: "price_feed",
+            run: price_feed,
+        },
+        Demo {
+            name: "orders",
+            run: orders,
+        },
+        Demo {
+            name: "profit_and_loss",
+            run: profit_and_loss,
+        },
+    ],
+};
+
+/*
+Project: a paper trading portfolio
+*/
+// the Broker of structs_enums::this_enum could only print "Buy, Buy, Buy!"
+// here it becomes a small trading simulator: brokers with fees, an account with cash and
+// positions, market and limit orders that are filled against prices from a CSV file,
+// profit and loss, and a ledger of every trade. Paper trading, so no real money is lost.
+// Nothing new in here, it's the earlier lessons used together
+
+//      Money
+// 0.1 + 0.2 != 0.3 with floats, and small rounding errors add up over many trades
+// so money is counted in whole cents, in a newtype that only allows what makes sense:
+// adding money, and multiplying a price with a quantity
+// an i64 of cents is more money than there is, but a typo in prices.csv can still claim
+// more than that, so parsing and multiplying are checked (see data_types, checked_mul)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Money(pub i64);
+
+impl Money {
+    // "101.5" and "101.50" are both 10150 cents
+    pub fn parse(text: &str) -> Option<Money> {
+        let (sign, text) = match text.strip_prefix('-') {
+            Some(text) => (-1, text),
+            None => (1, text),
+        };
+        let (euros, cents) = text.split_once('.').unwrap_or((text, "0"));
+        let digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+        if !digits(euros) || !digits(cents) || cents.len() > 2 {
+            return None;
+        }
+        let euros: i64 = euros.parse().ok()?;
+        let cents: i64 = format!("{cents:0<2}").parse().ok()?; // "5" is 50 cents
+        let total = euros.checked_mul(100)?.checked_add(cents)?;
+        Some(Money(sign * total))
+    }
+
+    // price * quantity, None if that is too much to count
+    pub fn checked_mul(self, quantity: u32) -> Option<Money> {
+        self.0.checked_mul(i64::from(quantity)).map(Money)
+    }
+
+    pub fn checked_add(self, other: Money) -> Option<Money> {
+        self.0.checked_add(other.0).map(Money)
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, other: Money) -> Money {
+        Money(self.0 + other.0)
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, other: Money) -> Money {
+        Money(self.0 - other.0)
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        write!(f, "{sign}{}.{:02}", self.0.abs() / 100, self.0.abs() % 100)
+    }
+}
+
+//      Brokers and their fees
+// the same two variants as in this_enum, the i32 of the BankDepot is now its commission
+// in basis points (hundredths of a percent)
+#[derive(Debug)]
+pub enum Broker {
+    Neobroker { name: String },
+    BankDepot(String, i32),
+}
+
+impl Broker {
+    pub fn name(&self) -> &str {
+        match self {
+            Broker::Neobroker { name } | Broker::BankDepot(name, _) => name,
+        }
+    }
+
+    // what one order of this value costs extra
+    pub fn fee(&self, value: Money) -> Money {
+        match self {
+            // one euro, no matter how big the order is
+            Broker::Neobroker { .. } => Money(100),
+            // a share of the value, rounded down, but at least 4.95
+            // in i128, the value times the basis points may not fit into an i64
+            Broker::BankDepot(_, basis_points) => {
+                let share = i128::from(value.0) * i128::from(*basis_points) / 10_000;
+                Money(i64::try_from(share).unwrap_or(i64::MAX)).max(Money(495))
+            }
+        }
+    }
+}
+
+fn brokers() {
+    let neobroker = Broker::Neobroker {
+        name: String::from("Trade Republic"),
+    };
+    let bank = Broker::BankDepot(String::from("Deutsche Bank"), 42);
+    for value in [Money(1_000), Money(500_000)] {
+        println!(
+            "{value}: {} {}, {} {}",
+            neobroker.name(),
+            neobroker.fee(value),
+            bank.name(),
+            bank.fee(value)
+        );
+    }
+    // "10.00: Trade Republic 1.00, Deutsche Bank 4.95"
+    // "5000.00: Trade Republic 1.00, Deutsche Bank 21.00"
+}
+
+//      The price feed
+// one price per line: date,symbol,price, the first line names the columns
+// the dates are ISO dates, so comparing them as text puts them in the right order
+pub struct Tick {
+    pub date: String,
+    pub symbol: String,
+    pub price: Money,
+}
+
+// errors say where they are, line numbers start at 1 like in an editor
+#[derive(Debug)]
+pub struct ParseError {
+    line: usize,
+    message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub struct PriceFeed {
+    ticks: Vec<Tick>,
+}
+
+impl PriceFeed {
+    pub fn from_csv(csv: &str) -> Result<PriceFeed, ParseError> {
+        let mut ticks: Vec<Tick> = Vec::new();
+        // skip(1): the header
+        for (index, line) in csv.lines().enumerate().skip(1) {
+            let error = |message: String| ParseError {
+                line: index + 1,
+                message,
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            let [date, symbol, price] = line.split(',').map(str::trim).collect::<Vec<_>>()[..]
+            else {
+                return Err(error(format!("expected date,symbol,price, got `{line}`")));
+            };
+            let price = Money::parse(price)
+                .filter(|price| price.0 > 0)
+                .ok_or_else(|| error(format!("price `{price}` is not a positive amount")))?;
+            if ticks.last().is_some_and(|last| last.date.as_str() > date) {
+                return Err(error(format!("{date} comes after a later date")));
+            }
+            ticks.push(Tick {
+                date: date.to_string(),
+                symbol: symbol.to_string(),
+                price,
+            });
+        }
+        Ok(PriceFeed { ticks })
+    }
+
+    pub fn ticks(&self) -> &[Tick] {
+        &self.ticks
+    }
+
+    pub fn days(&self) -> Vec<&str> {
+        let mut days: Vec<&str> = self.ticks.iter().map(|tick| tick.date.as_str()).collect();
+        days.dedup(); // the ticks are sorted, so the same days are next to each other
+        days
+    }
+
+    pub fn on<'a>(&'a self, date: &'a str) -> impl Iterator<Item = &'a Tick> {
+        self.ticks.iter().filter(move |tick| tick.date == date)
+    }
+}
+
+const PRICES: &str = include_str!("data/prices.csv");
+
+fn price_feed() {
+    let feed = PriceFeed::from_csv(PRICES).unwrap();
+    println!("{} ticks", feed.ticks().len()); // 8 ticks
+    println!("{}", feed.days().join(" ")); // 2024-03-01 2024-03-04 2024-03-05 2024-03-06
+    let first = &feed.ticks()[0];
+    println!("{} {} {}", first.date, first.symbol, first.price); // 2024-03-01 FERRIS 100.00
+
+    // ? would hand the error to our caller, here we only look at it
+    match PriceFeed::from_csv("date,symbol,price\n2024-03-01,CRAB,twenty") {
+        Ok(_) => println!("parsed"),
+        Err(e) => println!("{e}"), // line 2: price `twenty` is not a positive amount
+    }
+    // more cents than an i64 holds is an error too, not a panic
+    let csv = "date,symbol,price\n2024-03-01,CRAB,99999999999999999.00";
+    if let Err(e) = PriceFeed::from_csv(csv) {
+        println!("{e}"); // "line 2: price `99999999999999999.00` is not a positive amount"
+    }
+}
+
+//      Orders
+// market: buy or sell at whatever the price is now
+// limit: only at this price or better, else the order waits for a price that fits
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Side {
+    Buy,
+    Sell,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Limit {
+    Market,
+    At(Money),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Order {
+    pub side: Side,
+    pub symbol: String,
+    pub quantity: u32,
+    pub limit: Limit,
+}
+
+impl Order {
+    pub fn buy(symbol: &str, quantity: u32) -> Order {
+        Order {
+            side: Side::Buy,
+            symbol: symbol.to_string(),
+            quantity,
+            limit: Limit::Market,
+        }
+    }
+
+    pub fn sell(symbol: &str, quantity: u32) -> Order {
+        Order {
+            side: Side::Sell,
+            ..Order::buy(symbol, quantity)
+        }
+    }
+
+    // Order::buy("CRAB", 10).at(Money(1950))
+    pub fn at(self, limit: Money) -> Order {
+        Order {
+            limit: Limit::At(limit),
+            ..self
+        }
+    }
+
+    // what is wrong with the order no matter the price: nothing to trade, or a limit
+    // no price can reach (prices are above 0, see PriceFeed)
+    pub fn validate(&self) -> Result<(), OrderError> {
+        if self.quantity == 0 {
+            return Err(OrderError::NoQuantity);
+        }
+        match self.limit {
+            Limit::At(limit) if limit <= Money(0) => Err(OrderError::NoLimit(limit)),
+            _ => Ok(()),
+        }
+    }
+
+    // would this order be filled at this price?
+    pub fn accepts(&self, price: Money) -> bool {
+        match (self.limit, self.side) {
+            (Limit::Market, _) => true,
+            (Limit::At(limit), Side::Buy) => price <= limit,
+            (Limit::At(limit), Side::Sell) => price >= limit,
+        }
+    }
+}
+
+// everything that can go wrong with an order, the caller decides what to do about it
+#[derive(Debug, PartialEq)]
+pub enum OrderError {
+    NoQuantity,
+    NoLimit(Money),
+    NoPrice(String),
+    NotEnoughCash {
+        needed: Money,
+        available: Money,
+    },
+    NotEnoughShares {
+        symbol: String,
+        wanted: u32,
+        held: u32,
+    },
+    // more money or shares than an account can count
+    TooLarge,
+}
+
+impl fmt::Display for OrderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OrderError::NoQuantity => write!(f, "an order needs a quantity of at least 1"),
+            OrderError::NoLimit(limit) => write!(f, "a limit of {limit} is not a price"),
+            OrderError::NoPrice(symbol) => write!(f, "there is no price for {symbol} yet"),
+            OrderError::NotEnoughCash { needed, available } => {
+                write!(f, "needs {needed} but only {available} is available")
+            }
+            OrderError::NotEnoughShares {
+                symbol,
+                wanted,
+                held,
+            } => write!(f, "wants to sell {wanted} {symbol} but holds {held}"),
+            OrderError::TooLarge => write!(f, "the amount is too large for the account"),
+        }
+    }
+}
+
+impl std::error::Error for OrderError {}
+
+//      The account
+// cash, positions and the ledger can only change through filled orders,
+// so the fields are private (see oop::encapsulation)
+#[derive(Debug, Default)]
+pub struct Position {
+    pub quantity: u32,
+    // everything paid for the shares still held, fees included
+    pub cost: Money,
+}
+
+// one line of the ledger, a filled order
+#[derive(Debug)]
+pub struct Transaction {
+    pub date: String,
+    pub side: Side,
+    pub symbol: String,
+    pub quantity: u32,
+    pub price: Money,
+    pub fee: Money,
+    // what a sale earned compared to what the shares cost, None for purchases
+    pub realised: Option<Money>,
+}
+
+impl fmt::Display for Transaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let side = match self.side {
+            Side::Buy => "buy",
+            Side::Sell => "sell",
+        };
+        write!(
+            f,
+            "{} {side} {} {} at {}, fee {}",
+            self.date, self.quantity, self.symbol, self.price, self.fee
+        )?;
+        if let Some(realised) = self.realised {
+            write!(f, ", realised {realised}")?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Account {
+    broker: Broker,
+    cash: Money,
+    // BTreeMap instead of HashMap: the positions are listed in the same order every time
+    positions: BTreeMap<String, Position>,
+    prices: BTreeMap<String, Money>,
+    date: String,
+    waiting: Vec<Order>,
+    ledger: Vec<Transaction>,
+}
+
+impl Account {
+    pub fn new(broker: Broker, cash: Money) -> Account {
+        Account {
+            broker,
+            cash,
+            positions: BTreeMap::new(),
+            prices: BTreeMap::new(),
+            date: String::new(),
+            waiting: Vec::new(),
+            ledger: Vec::new(),
+        }
+    }
+
+    pub fn cash(&self) -> Money {
+        self.cash
+    }
+
+    pub fn position(&self, symbol: &str) -> Option<&Position> {
+        self.positions.get(symbol)
+    }
+
+    pub fn ledger(&self) -> &[Transaction] {
+        &self.ledger
+    }
+
+    // limit orders that didn't get their price yet
+    pub fn waiting(&self) -> &[Order] {
+        &self.waiting
+    }
+
+    // a market order is filled right away at the last price,
+    // a limit order too if the price fits, else it waits for the next ticks
+    pub fn place(&mut self, order: Order) -> Result<Option<&Transaction>, OrderError> {
+        order.validate()?;
+        let price = self.prices.get(&order.symbol).copied();
+        match (price, order.limit) {
+            (Some(price), _) if order.accepts(price) => self.fill(&order, price).map(Some),
+            (None, Limit::Market) => Err(OrderError::NoPrice(order.symbol)),
+            _ => {
+                self.waiting.push(order);
+                Ok(None)
+            }
+        }
+    }
+
+    // the old buy_in and sell_out, now they trade
+    pub fn buy_in(
+        &mut self,
+        symbol: &str,
+        quantity: u32,
+    ) -> Result<Option<&Transaction>, OrderError> {
+        self.place(Order::buy(symbol, quantity))
+    }
+
+    // sells the whole position
+    pub fn sell_out(&mut self, symbol: &str) -> Result<Option<&Transaction>, OrderError> {
+        let held = self
+            .position(symbol)
+            .map_or(0, |position| position.quantity);
+        self.place(Order::sell(symbol, held))
+    }
+
+    // a new price: remember it and fill the waiting orders it fits, in the order they
+    // were placed. Orders that can't be paid anymore are dropped, with the reason
+    pub fn tick(&mut self, tick: &Tick) -> Vec<Result<String, OrderError>> {
+        self.date.clone_from(&tick.date);
+        self.prices.insert(tick.symbol.clone(), tick.price);
+        let (ready, waiting) = std::mem::take(&mut self.waiting)
+            .into_iter()
+            .partition(|order: &Order| order.symbol == tick.symbol && order.accepts(tick.price));
+        self.waiting = waiting;
+        ready
+            .iter()
+            .map(|order| self.fill(order, tick.price).map(|t| t.to_string()))
+            .collect()
+    }
+
+    // would the account have the cash or the shares for this? Changes nothing
+    pub fn check(
+        &self,
+        side: Side,
+        symbol: &str,
+        quantity: u32,
+        price: Money,
+    ) -> Result<(), OrderError> {
+        let value = price.checked_mul(quantity).ok_or(OrderError::TooLarge)?;
+        match side {
+            Side::Buy => {
+                let needed = value
+                    .checked_add(self.broker.fee(value))
+                    .ok_or(OrderError::TooLarge)?;
+                if needed > self.cash {
+                    return Err(OrderError::NotEnoughCash {
+                        needed,
+                        available: self.cash,
+                    });
+                }
+                // the position grows by the quantity and its cost by what was paid
+                let position = self.position(symbol);
+                let held = position.map_or(0, |p| p.quantity);
+                let cost = position.map_or(Money(0), |p| p.cost);
+                if held.checked_add(quantity).is_none() || cost.checked_add(needed).is_none() {
+                    return Err(OrderError::TooLarge);
+                }
+            }
+            Side::Sell => {
+                let held = self.position(symbol).map_or(0, |p| p.quantity);
+                if quantity > held {
+                    return Err(OrderError::NotEnoughShares {
+                        symbol: symbol.to_string(),
+                        wanted: quantity,
+                        held,
+                    });
+                }
+                // the fee is paid from the cash and what the sale brings in, a sale
+                // worth less than the fee can only be made with enough cash left
+                let available = self.cash.checked_add(value).ok_or(OrderError::TooLarge)?;
+                let fee = self.broker.fee(value);
+                if fee > available {
+                    return Err(OrderError::NotEnoughCash {
+                        needed: fee,
+                        available,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // a trade that was made somewhere else, on an exchange (see order_book)
+    pub fn trade(
+        &mut self,
+        date: &str,
+        side: Side,
+        symbol: &str,
+        quantity: u32,
+        price: Money,
+    ) -> Result<&Transaction, OrderError> {
+        self.date = date.to_string();
+        self.prices.insert(symbol.to_string(), price);
+        let order = Order {
+            side,
+            ..Order::buy(symbol, quantity)
+        };
+        self.fill(&order, price)
+    }
+
+    fn fill(&mut self, order: &Order, price: Money) -> Result<&Transaction, OrderError> {
+        // after check nothing below can overflow
+        self.check(order.side, &order.symbol, order.quantity, price)?;
+        let value = price
+            .checked_mul(order.quantity)
+            .ok_or(OrderError::TooLarge)?;
+        let fee = self.broker.fee(value);
+        let realised = match order.side {
+            Side::Buy => {
+                self.cash = self.cash - value - fee;
+                let position = self.positions.entry(order.symbol.clone()).or_default();
+                position.quantity += order.quantity;
+                position.cost = position.cost + value + fee;
+                None
+            }
+            Side::Sell => {
+                // check made sure there is a position with enough shares
+                let position = self.positions.get_mut(&order.symbol).unwrap();
+                let held = position.quantity;
+                // the sold shares take their part of the cost with them, at the average price
+                // in i128, cost times quantity may not fit into an i64, the result does
+                let share = i128::from(position.cost.0) * i128::from(order.quantity);
+                let cost = Money((share / i128::from(held)) as i64);
+                position.quantity -= order.quantity;
+                position.cost = position.cost - cost;
+                if position.quantity == 0 {
+                    self.positions.remove(&order.symbol);
+                }
+                self.cash = self.cash + value - fee;
+                Some(value - fee - cost)
+            }
+        };
+        self.ledger.push(Transaction {
+            date: self.date.clone(),
+            side: order.side,
+            symbol: order.symbol.clone(),
+            quantity: order.quantity,
+            price,
+            fee,
+            realised,
+        });
+        Ok(self.ledger.last().unwrap()) // pushed one line ago
+    }
+
+    // profit of everything sold
+    pub fn realised(&self) -> Money {
+        self.ledger
+            .iter()
+            .filter_map(|transaction| transaction.realised)
+            .fold(Money(0), |sum, realised| sum + realised)
+    }
+
+    // what a position is worth at the last price, None if that doesn't fit into an i64
+    fn worth(&self, symbol: &str, position: &Position) -> Option<Money> {
+        self.prices[symbol].checked_mul(position.quantity)
+    }
+
+    // profit if everything held was sold at the last price, fees of the sale not included
+    // the prices can rise after buying, so this can be too much to count
+    pub fn unrealised(&self) -> Option<Money> {
+        self.positions
+            .iter()
+            .try_fold(Money(0), |sum, (symbol, position)| {
+                sum.checked_add(self.worth(symbol, position)? - position.cost)
+            })
+    }
+
+    // cash and everything held at the last price
+    pub fn value(&self) -> Option<Money> {
+        self.positions
+            .iter()
+            .try_fold(self.cash, |sum, (symbol, position)| {
+                sum.checked_add(self.worth(symbol, position)?)
+            })
+    }
+}
+
+// feeds one day of prices into the account and prints what got filled
+fn trading_day(account: &mut Account, feed: &PriceFeed, date: &str) {
+    for tick in feed.on(date) {
+        for fill in account.tick(tick) {
+            match fill {
+                Ok(transaction) => println!("filled: {transaction}"),
+                Err(e) => println!("dropped: {e}"),
+            }
+        }
+    }
+}
+
+fn orders() {
+    let feed = PriceFeed::from_csv(PRICES).unwrap();
+    let broker = Broker::Neobroker {
+        name: String::from("Trade Republic"),
+    };
+    let mut account = Account::new(broker, Money(100_000));
+    // before the first price nobody knows what a market order would cost
+    println!("{:?}", account.buy_in("FERRIS", 5).unwrap_err()); // NoPrice("FERRIS")
+
+    trading_day(&mut account, &feed, "2024-03-01");
+    if let Ok(Some(transaction)) = account.buy_in("FERRIS", 5) {
+        println!("{transaction}"); // "2024-03-01 buy 5 FERRIS at 100.00, fee 1.00"
+    }
+    println!("{}", account.cash()); // 499.00
+
+    // CRAB costs 20.00, we only want it for 19.50 or less
+    let crab = Order::buy("CRAB", 10).at(Money(1950));
+    println!("{:?}", account.place(crab).map(|filled| filled.is_some())); // Ok(false)
+    println!("{}", account.waiting().len()); // 1
+    trading_day(&mut account, &feed, "2024-03-04"); // "filled: 2024-03-04 buy 10 CRAB at 19.20, fee 1.00"
+    println!("{} {}", account.cash(), account.waiting().len()); // 306.00 0
+
+    // the errors are values, the account stays as it was
+    let too_expensive = account.buy_in("FERRIS", 10).unwrap_err();
+    println!("{too_expensive}"); // needs 1046.00 but only 306.00 is available
+    let too_many = account.place(Order::sell("CRAB", 11)).unwrap_err();
+    println!("{too_many}"); // wants to sell 11 CRAB but holds 10
+}
+
+fn profit_and_loss() {
+    let feed = PriceFeed::from_csv(PRICES).unwrap();
+    let broker = Broker::BankDepot(String::from("Deutsche Bank"), 42);
+    let mut account = Account::new(broker, Money(500_000));
+    trading_day(&mut account, &feed, "2024-03-01");
+    account.buy_in("FERRIS", 20).unwrap(); // 2000.00 + 8.40 fee
+    account.buy_in("CRAB", 50).unwrap(); // 1000.00 + 4.95 fee, the minimum
+
+    // take the profit once FERRIS is at 108.00
+    account
+        .place(Order::sell("FERRIS", 10).at(Money(10_800)))
+        .unwrap();
+
+    trading_day(&mut account, &feed, "2024-03-04");
+    trading_day(&mut account, &feed, "2024-03-05");
+    // FERRIS at 98.00: 20 shares bought for 2008.40 are worth 1960.00,
+    // CRAB gained 65.05 after its fee
+    println!("{} {}", account.realised(), account.unrealised().unwrap()); // 0.00 16.65
+
+    trading_day(&mut account, &feed, "2024-03-06"); // "filled: 2024-03-06 sell 10 FERRIS at 110.25, fee 4.95, realised 93.35"
+    account.sell_out("CRAB").unwrap();
+    println!("{}", account.realised()); // 183.45
+    println!("{}", account.unrealised().unwrap()); // 98.30
+    println!("{}", account.value().unwrap()); // 5281.75
+
+    println!("{:?}", account.position("CRAB")); // None
+    let ferris = account.position("FERRIS").unwrap();
+    println!("{} for {}", ferris.quantity, ferris.cost); // 10 for 1004.20
+
+    // the ledger has every trade, in the order they happened
+    for transaction in account.ledger() {
+        println!("{transaction}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Account, Broker, Money, Order, OrderError, Side, Tick};
+
+    fn neobroker(cash: Money) -> Account {
+        let name = String::from("Trade Republic");
+        Account::new(Broker::Neobroker { name }, cash)
+    }
+
+    #[test]
+    fn money_parses_euros_and_cents() {
+        assert_eq!(Money::parse("101.5"), Some(Money(10_150)));
+        assert_eq!(Money::parse("101.50"), Some(Money(10_150)));
+        assert_eq!(Money::parse("7"), Some(Money(700)));
+        assert_eq!(Money::parse("-0.05"), Some(Money(-5)));
+        for text in ["", "twenty", "1.234", ".5", "1.", "1,50", "+1", "1.-5"] {
+            assert_eq!(Money::parse(text), None, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn money_parse_refuses_more_than_an_i64_of_cents() {
+        assert_eq!(Money::parse("92233720368547758.07"), Some(Money(i64::MAX)));
+        assert_eq!(Money::parse("92233720368547758.08"), None);
+        assert_eq!(Money::parse("99999999999999999999"), None);
+    }
+
+    #[test]
+    fn fills_move_cash_and_cost_and_realise_the_profit() {
+        let mut account = neobroker(Money(100_000));
+        account
+            .trade("2024-03-01", Side::Buy, "CRAB", 10, Money(1_000))
+            .unwrap();
+        // 100.00 and the fee of 1.00
+        assert_eq!(account.cash(), Money(89_900));
+        assert_eq!(account.position("CRAB").unwrap().cost, Money(10_100));
+
+        let sold = account
+            .trade("2024-03-04", Side::Sell, "CRAB", 4, Money(1_200))
+            .unwrap();
+        // 48.00 minus the fee minus 4/10 of the cost
+        assert_eq!(sold.realised, Some(Money(4_800 - 100 - 4_040)));
+        let position = account.position("CRAB").unwrap();
+        assert_eq!((position.quantity, position.cost), (6, Money(6_060)));
+        assert_eq!(account.cash(), Money(89_900 + 4_800 - 100));
+
+        account
+            .trade("2024-03-05", Side::Sell, "CRAB", 6, Money(900))
+            .unwrap();
+        assert!(account.position("CRAB").is_none());
+        assert_eq!(account.realised(), Money(660 + 5_400 - 100 - 6_060));
+        assert_eq!(account.value(), Some(account.cash()));
+    }
+
+    #[test]
+    fn limit_orders_wait_for_their_price() {
+        let mut account = neobroker(Money(100_000));
+        let order = Order::buy("CRAB", 10).at(Money(1_000));
+        assert!(account.place(order).unwrap().is_none());
+        let tick = |price| Tick {
+            date: String::from("2024-03-01"),
+            symbol: String::from("CRAB"),
+            price: Money(price),
+        };
+        assert!(account.tick(&tick(1_050)).is_empty());
+        assert_eq!(account.waiting().len(), 1);
+        assert_eq!(account.tick(&tick(990)).len(), 1);
+        assert!(account.waiting().is_empty());
+        assert_eq!(account.ledger()[0].price, Money(990));
+    }
+
+    #[test]
+    fn a_sale_needs_cash_for_a_fee_larger_than_its_value() {
+        let bank = Broker::BankDepot(String::from("Deutsche Bank"), 42);
+        let mut account = Account::new(bank, Money(1_000));
+        // 1.00 and the minimum fee of 4.95
+        account
+            .trade("2024-03-01", Side::Buy, "CRAB", 1, Money(100))
+            .unwrap();
+        assert_eq!(account.cash(), Money(405));
+        let refused = account.trade("2024-03-04", Side::Sell, "CRAB", 1, Money(1));
+        assert_eq!(
+            refused.unwrap_err(),
+            OrderError::NotEnoughCash {
+                needed: Money(495),
+                available: Money(406),
+            }
+        );
+        assert_eq!(account.cash(), Money(405));
+        assert_eq!(account.position("CRAB").unwrap().quantity, 1);
+    }
+}
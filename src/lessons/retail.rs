@@ -0,0 +1,530 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::rc::Rc;
+
+use super::portfolio::Money;
+use super::{Demo, Lesson};
+
+pub const LESSON: Lesson = Lesson {
+    name: "retail",
+    source: include_str!("retail.rs"),
+    requires: &["portfolio", "smart_pointers"],
+    demos: &[
+        Demo {
+            name: "channels",
+            run: channels,
+        },
+        Demo {
+            name: "checkout",
+            run: checkout,
+        },
+        Demo {
+            name: "reservations",
+            run: reservations,
+        },
+    ],
+};
+
+/*
+Project: a store with several channels
+*/
+// the Store of structs_enums::this_enum only knew "Beep Boop" and "Cash please!", and its
+// match ended with _ => () so Brand and PopUp did nothing
+// here every channel has its own payment methods, taxes and stock, and every match on Store
+// lists all four variants. A fifth channel would be a compile error in each of them until
+// it is handled, with _ => () it would silently do nothing
+// money is the Money of the portfolio project, in cents
+
+//      Channels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Store {
+    Online,
+    Local,
+    Brand,
+    PopUp,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Payment {
+    Cash,
+    Card,
+    PayPal,
+    GiftCard,
+}
+
+// the VAT rate of a product, standard or reduced (books, food)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Category {
+    Standard,
+    Reduced,
+}
+
+impl Store {
+    pub const ALL: [Store; 4] = [Store::Online, Store::Local, Store::Brand, Store::PopUp];
+
+    pub fn greeting(self) -> &'static str {
+        match self {
+            Store::Online => "Beep Boop",
+            Store::Local => "Cash please!",
+            Store::Brand => "Welcome to the flagship store",
+            Store::PopUp => "Only today!",
+        }
+    }
+
+    pub fn payments(self) -> &'static [Payment] {
+        match self {
+            Store::Online => &[Payment::Card, Payment::PayPal, Payment::GiftCard],
+            Store::Local => &[Payment::Cash, Payment::Card],
+            Store::Brand => &[Payment::Card, Payment::GiftCard],
+            Store::PopUp => &[Payment::Cash],
+        }
+    }
+
+    // VAT in basis points, the pop-up stall is a small business that charges none
+    pub fn tax(self, category: Category) -> i64 {
+        match (self, category) {
+            (Store::PopUp, _) => 0,
+            (Store::Online | Store::Local | Store::Brand, Category::Standard) => 1_900,
+            (Store::Online | Store::Local | Store::Brand, Category::Reduced) => 700,
+        }
+    }
+
+    // the stock pools a channel may sell from, its own first
+    // online orders are shipped from the shops too, the pop-up gets the local shop's stock
+    pub fn pools(self) -> &'static [Store] {
+        match self {
+            Store::Online => &[Store::Online, Store::Local, Store::Brand],
+            Store::Local => &[Store::Local],
+            Store::Brand => &[Store::Brand],
+            Store::PopUp => &[Store::PopUp, Store::Local],
+        }
+    }
+}
+
+fn channels() {
+    for store in Store::ALL {
+        println!("{store:?}: {}", store.greeting());
+    }
+    // "Online: Beep Boop"
+    // "Local: Cash please!"
+    println!("{:?}", Store::PopUp.payments()); // [Cash]
+    println!("{}", Store::Online.payments().contains(&Payment::Cash)); // false
+    let taxes = Store::ALL.map(|store| store.tax(Category::Standard));
+    println!("{taxes:?}"); // "[1900, 1900, 1900, 0]"
+    println!("{:?}", Store::PopUp.pools()); // "[PopUp, Local]"
+}
+
+//      Catalogue and stock
+#[derive(Debug, Clone)]
+pub struct Product {
+    pub sku: String,
+    pub name: String,
+    // without VAT, the channel adds it at checkout
+    pub price: Money,
+    pub category: Category,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RetailError {
+    NoQuantity,
+    UnknownProduct(String),
+    OutOfStock {
+        sku: String,
+        wanted: u32,
+        available: u32,
+    },
+    PaymentNotAccepted(Store, Payment),
+    EmptyCart,
+    // a total that doesn't fit into Money, or stock that doesn't fit into a u32
+    TooLarge,
+    // the cart holds reservations in the inventory of another shop
+    OtherShop,
+}
+
+impl fmt::Display for RetailError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RetailError::NoQuantity => write!(f, "a cart line needs a quantity of at least 1"),
+            RetailError::UnknownProduct(sku) => write!(f, "there is no product {sku}"),
+            RetailError::OutOfStock {
+                sku,
+                wanted,
+                available,
+            } => write!(f, "wanted {wanted} of {sku}, only {available} available"),
+            RetailError::PaymentNotAccepted(store, payment) => {
+                write!(f, "the {store:?} store doesn't take {payment:?}")
+            }
+            RetailError::EmptyCart => write!(f, "the cart is empty"),
+            RetailError::TooLarge => write!(f, "the amount is too large to count"),
+            RetailError::OtherShop => write!(f, "the cart belongs to another shop"),
+        }
+    }
+}
+
+impl std::error::Error for RetailError {}
+
+// stock that is in a cart is reserved: still on the shelf, but no other cart gets it
+#[derive(Debug)]
+pub struct Reservation {
+    pub pool: Store,
+    pub sku: String,
+    pub quantity: u32,
+}
+
+#[derive(Default)]
+pub struct Inventory {
+    // everything on the shelves, reserved items included
+    stock: BTreeMap<(Store, String), u32>,
+    reserved: BTreeMap<(Store, String), u32>,
+}
+
+impl Inventory {
+    pub fn restock(&mut self, pool: Store, sku: &str, quantity: u32) -> Result<(), RetailError> {
+        let stock = self.stock.entry((pool, sku.to_string())).or_default();
+        *stock = stock.checked_add(quantity).ok_or(RetailError::TooLarge)?;
+        Ok(())
+    }
+
+    pub fn available(&self, pool: Store, sku: &str) -> u32 {
+        let key = (pool, sku.to_string());
+        let stock = self.stock.get(&key).copied().unwrap_or(0);
+        stock - self.reserved.get(&key).copied().unwrap_or(0)
+    }
+
+    // takes from the channel's pools in order, all or nothing
+    fn reserve(
+        &mut self,
+        store: Store,
+        sku: &str,
+        quantity: u32,
+    ) -> Result<Vec<Reservation>, RetailError> {
+        // every pool may hold up to u32::MAX on its own
+        let available = store
+            .pools()
+            .iter()
+            .try_fold(0u32, |sum, &pool| {
+                sum.checked_add(self.available(pool, sku))
+            })
+            .ok_or(RetailError::TooLarge)?;
+        if quantity > available {
+            return Err(RetailError::OutOfStock {
+                sku: sku.to_string(),
+                wanted: quantity,
+                available,
+            });
+        }
+        let mut reservations = Vec::new();
+        let mut missing = quantity;
+        for &pool in store.pools() {
+            let taken = missing.min(self.available(pool, sku));
+            if taken > 0 {
+                *self.reserved.entry((pool, sku.to_string())).or_default() += taken;
+                reservations.push(Reservation {
+                    pool,
+                    sku: sku.to_string(),
+                    quantity: taken,
+                });
+                missing -= taken;
+            }
+        }
+        Ok(reservations)
+    }
+
+    // back on the shelf for everyone
+    fn release(&mut self, reservation: &Reservation) {
+        let key = (reservation.pool, reservation.sku.clone());
+        if let Some(reserved) = self.reserved.get_mut(&key) {
+            *reserved -= reservation.quantity;
+        }
+    }
+
+    // sold, off the shelf
+    fn commit(&mut self, reservation: &Reservation) {
+        self.release(reservation);
+        let key = (reservation.pool, reservation.sku.clone());
+        if let Some(stock) = self.stock.get_mut(&key) {
+            *stock -= reservation.quantity;
+        }
+    }
+}
+
+//      Carts and checkout
+// a cart shares the inventory with the shop and every other cart, Rc<RefCell<..>>
+// (smart_pointers::combined_rc_refcell). When a cart is dropped without a checkout, Drop
+// gives its reservations back, so an abandoned cart can't hold stock forever
+pub struct Cart {
+    store: Store,
+    inventory: Rc<RefCell<Inventory>>,
+    lines: Vec<(Product, u32)>,
+    reservations: Vec<Reservation>,
+}
+
+impl Cart {
+    pub fn store(&self) -> Store {
+        self.store
+    }
+
+    pub fn reservations(&self) -> &[Reservation] {
+        &self.reservations
+    }
+}
+
+impl Drop for Cart {
+    fn drop(&mut self) {
+        let mut inventory = self.inventory.borrow_mut();
+        for reservation in &self.reservations {
+            inventory.release(reservation);
+        }
+    }
+}
+
+pub struct ReceiptLine {
+    pub name: String,
+    pub quantity: u32,
+    pub total: Money,
+}
+
+pub struct Receipt {
+    pub number: u32,
+    pub store: Store,
+    pub lines: Vec<ReceiptLine>,
+    // VAT per rate in basis points, with the amount it is charged on
+    pub taxes: BTreeMap<i64, (Money, Money)>,
+    pub total: Money,
+    pub payment: Payment,
+}
+
+impl fmt::Display for Receipt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Money's Display ignores widths like {:>8}, to_string first gives a &str that has them
+        writeln!(f, "receipt {} ({:?})", self.number, self.store)?;
+        for line in &self.lines {
+            let total = line.total.to_string();
+            writeln!(f, "{:>4} x {:<16} {total:>8}", line.quantity, line.name)?;
+        }
+        for (rate, (net, tax)) in &self.taxes {
+            let label = format!("VAT {}% on {net}", rate / 100);
+            writeln!(f, "  {label:<21} {:>8}", tax.to_string())?;
+        }
+        let label = format!("total {:?}", self.payment);
+        write!(f, "  {label:<21} {:>8}", self.total.to_string())
+    }
+}
+
+pub struct Shop {
+    catalogue: BTreeMap<String, Product>,
+    inventory: Rc<RefCell<Inventory>>,
+    receipts: u32,
+}
+
+impl Shop {
+    pub fn new(products: Vec<Product>) -> Shop {
+        Shop {
+            catalogue: products
+                .into_iter()
+                .map(|product| (product.sku.clone(), product))
+                .collect(),
+            inventory: Rc::new(RefCell::new(Inventory::default())),
+            receipts: 0,
+        }
+    }
+
+    pub fn restock(&self, pool: Store, sku: &str, quantity: u32) -> Result<(), RetailError> {
+        self.inventory.borrow_mut().restock(pool, sku, quantity)
+    }
+
+    pub fn available(&self, pool: Store, sku: &str) -> u32 {
+        self.inventory.borrow().available(pool, sku)
+    }
+
+    pub fn cart(&self, store: Store) -> Cart {
+        Cart {
+            store,
+            inventory: Rc::clone(&self.inventory),
+            lines: Vec::new(),
+            reservations: Vec::new(),
+        }
+    }
+
+    // the cart reserves in the inventory it shares, and gives back there when dropped
+    // a cart of another shop would reserve here and release there
+    fn owns(&self, cart: &Cart) -> Result<(), RetailError> {
+        match Rc::ptr_eq(&self.inventory, &cart.inventory) {
+            true => Ok(()),
+            false => Err(RetailError::OtherShop),
+        }
+    }
+
+    pub fn add(&self, cart: &mut Cart, sku: &str, quantity: u32) -> Result<(), RetailError> {
+        self.owns(cart)?;
+        if quantity == 0 {
+            return Err(RetailError::NoQuantity);
+        }
+        let product = self
+            .catalogue
+            .get(sku)
+            .ok_or_else(|| RetailError::UnknownProduct(sku.to_string()))?;
+        let reserved = self
+            .inventory
+            .borrow_mut()
+            .reserve(cart.store, sku, quantity)?;
+        cart.reservations.extend(reserved);
+        cart.lines.push((product.clone(), quantity));
+        Ok(())
+    }
+
+    // pays, takes the items off the shelves and empties the cart
+    // on an error nothing changes and the cart keeps its reservations
+    pub fn checkout(&mut self, cart: &mut Cart, payment: Payment) -> Result<Receipt, RetailError> {
+        self.owns(cart)?;
+        if !cart.store.payments().contains(&payment) {
+            return Err(RetailError::PaymentNotAccepted(cart.store, payment));
+        }
+        if cart.lines.is_empty() {
+            return Err(RetailError::EmptyCart);
+        }
+        // the receipt first, an amount too large to count must not change anything either
+        let too_large = || RetailError::TooLarge;
+        let mut lines = Vec::new();
+        let mut taxes: BTreeMap<i64, (Money, Money)> = BTreeMap::new();
+        for (product, quantity) in &cart.lines {
+            let total = product.price.checked_mul(*quantity).ok_or_else(too_large)?;
+            let (net, _) = taxes.entry(cart.store.tax(product.category)).or_default();
+            *net = net.checked_add(total).ok_or_else(too_large)?;
+            lines.push(ReceiptLine {
+                name: product.name.clone(),
+                quantity: *quantity,
+                total,
+            });
+        }
+        // VAT is rounded once per rate, half a cent up
+        for (rate, (net, tax)) in &mut taxes {
+            let cents = net
+                .0
+                .checked_mul(*rate)
+                .and_then(|cents| cents.checked_add(5_000));
+            *tax = Money(cents.ok_or_else(too_large)? / 10_000);
+        }
+        let total = taxes
+            .values()
+            .try_fold(Money(0), |sum, &(net, tax)| {
+                sum.checked_add(net)?.checked_add(tax)
+            })
+            .ok_or_else(too_large)?;
+
+        let mut inventory = self.inventory.borrow_mut();
+        for reservation in cart.reservations.drain(..) {
+            inventory.commit(&reservation);
+        }
+        cart.lines.clear();
+        self.receipts += 1;
+        Ok(Receipt {
+            number: self.receipts,
+            store: cart.store,
+            lines,
+            taxes,
+            total,
+            payment,
+        })
+    }
+}
+
+fn product(sku: &str, name: &str, cents: i64, category: Category) -> Product {
+    Product {
+        sku: sku.to_string(),
+        name: name.to_string(),
+        price: Money(cents),
+        category,
+    }
+}
+
+fn shop() -> Shop {
+    let shop = Shop::new(vec![
+        product("MUG", "Ferris mug", 1_200, Category::Standard),
+        product("BOOK", "The Rust book", 3_990, Category::Reduced),
+        product("PLUSH", "Ferris plush", 2_500, Category::Standard),
+    ]);
+    let stock = [
+        (Store::Online, "MUG", 2),
+        (Store::Local, "MUG", 5),
+        (Store::Local, "BOOK", 3),
+        (Store::Brand, "PLUSH", 4),
+        (Store::PopUp, "PLUSH", 1),
+    ];
+    for (pool, sku, quantity) in stock {
+        shop.restock(pool, sku, quantity).unwrap();
+    }
+    shop
+}
+
+fn checkout() {
+    let mut shop = shop();
+    let mut cart = shop.cart(Store::Local);
+    shop.add(&mut cart, "MUG", 2).unwrap();
+    shop.add(&mut cart, "BOOK", 1).unwrap();
+    println!("{}", shop.add(&mut cart, "SOCKS", 1).unwrap_err()); // there is no product SOCKS
+
+    // the local shop doesn't do PayPal, the cart is still there afterwards
+    let paypal = shop.checkout(&mut cart, Payment::PayPal);
+    println!("{}", paypal.err().unwrap()); // the Local store doesn't take PayPal
+    let receipt = shop.checkout(&mut cart, Payment::Cash).unwrap();
+    println!("{receipt}");
+    // receipt 1 (Local)
+    //    2 x Ferris mug          24.00
+    //    1 x The Rust book       39.90
+    //   VAT 7% on 39.90           2.79
+    //   VAT 19% on 24.00          4.56
+    //   total Cash               71.25
+    println!("{}", receipt.total); // 71.25
+    println!("{}", shop.available(Store::Local, "MUG")); // 3
+
+    // the same mug at the pop-up stall, no VAT there
+    let mut cart = shop.cart(Store::PopUp);
+    shop.add(&mut cart, "MUG", 1).unwrap(); // from the local shop's pool
+    let receipt = shop.checkout(&mut cart, Payment::Cash).unwrap();
+    println!("{} {}", receipt.number, receipt.total); // 2 12.00
+    println!("{:?}", shop.checkout(&mut cart, Payment::Cash).err()); // Some(EmptyCart)
+}
+
+fn reservations() {
+    let mut shop = shop();
+    // online has 2 mugs of its own, the other 3 are reserved in the local shop
+    let mut online = shop.cart(Store::Online);
+    shop.add(&mut online, "MUG", 5).unwrap();
+    for reservation in online.reservations() {
+        println!("{:?} {}", reservation.pool, reservation.quantity);
+    }
+    // Online 2
+    // Local 3
+    println!("{}", shop.available(Store::Local, "MUG")); // 2
+
+    // a customer in the local shop can't take the reserved ones
+    let mut local = shop.cart(Store::Local);
+    let error = shop.add(&mut local, "MUG", 3).unwrap_err();
+    println!("{error}"); // "wanted 3 of MUG, only 2 available"
+    println!("{:?}", local.store()); // Local
+
+    // the online customer leaves, dropping the cart puts the mugs back
+    drop(online);
+    println!("{}", shop.available(Store::Local, "MUG")); // 5
+    shop.add(&mut local, "MUG", 3).unwrap();
+    let receipt = shop.checkout(&mut local, Payment::Card).unwrap();
+    println!("{}", receipt.total); // 42.84
+    println!("{}", shop.available(Store::Local, "MUG")); // 2
+
+    // the brand store only has its own plushies, the pop-up can't send any
+    let mut brand = shop.cart(Store::Brand);
+    let error = shop.add(&mut brand, "MUG", 1).unwrap_err();
+    println!("{error}"); // "wanted 1 of MUG, only 0 available"
+    println!("{}", shop.add(&mut brand, "PLUSH", 0).unwrap_err()); // a cart line needs a quantity of at least 1
+    println!(
+        "{}",
+        shop.restock(Store::Brand, "PLUSH", u32::MAX).unwrap_err()
+    ); // the amount is too large to count
+
+    // a cart only works in the shop it came from, its reservations are in that inventory
+    let other = self::shop();
+    let mut foreign = other.cart(Store::Local);
+    println!("{}", shop.add(&mut foreign, "MUG", 1).unwrap_err()); // the cart belongs to another shop
+}
@@ -0,0 +1,150 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::{Demo, Lesson};
+
+pub const LESSON: Lesson = Lesson {
+    name: "smart_pointers",
+    source: include_str!("smart_pointers.rs"),
+    requires: &["structs_enums", "traits"],
+    demos: &[
+        Demo {
+            name: "recursive_list",
+            run: recursive_list,
+        },
+        Demo {
+            name: "reference_counting",
+            run: reference_counting,
+        },
+        Demo {
+            name: "refcell",
+            run: refcell,
+        },
+        Demo {
+            name: "combined_rc_refcell",
+            run: combined_rc_refcell,
+        },
+    ],
+};
+
+/*
+Smart Pointers
+*/
+// Ownership and Borrowing ? Some things change.
+// Smart pointers are pointers but also have additional metadata and capabilities
+
+// every List lives in its own module, as all three are called List
+
+// Recursive Types with Box
+// Box<T> for allocating values on the heap
+// Cons(i32, List) without the Box has an infinite size (compile-fail recursive_without_box)
+mod boxed {
+    #[derive(Debug)]
+    pub enum List {
+        Cons(i32, Box<List>), // every Element contains a value and the next part of the List
+        Nil,                  // the end of the list
+    }
+
+    impl List {
+        // walking the list is recursive too
+        pub fn sum(&self) -> i32 {
+            match self {
+                List::Cons(value, next) => value + next.sum(),
+                List::Nil => 0,
+            }
+        }
+    }
+}
+
+fn recursive_list() {
+    use boxed::List::{Cons, Nil};
+    // Box is basically a pointer to a sized Object
+    // hence the Box offers "indirection", so the compiler knows about the size of the list, value is now allocated on heap!
+    let list = Cons(32, Box::new(Cons(12, Box::new(Nil))));
+    println!("{:?}", list);
+    println!("{}", list.sum()); // 44
+    let a = 45;
+    let b = Box::new(a);
+    println!("{}", *b); // 45
+}
+
+// Reference Counting
+//Rc<T> a reference counting type that enables multiple ownership
+// It counts the amount of owners of a value
+// creating a new owner is done by cloning the reference
+mod counted {
+    use std::rc::Rc;
+
+    #[derive(Debug)]
+    pub enum List {
+        Cons(i32, Rc<List>), // every Element contains a value and the next part of the List
+        Nil,                 // the end of the list
+    }
+
+    impl List {
+        pub fn sum(&self) -> i32 {
+            match self {
+                List::Cons(value, next) => value + next.sum(),
+                List::Nil => 0,
+            }
+        }
+    }
+}
+
+fn reference_counting() {
+    use counted::List::{Cons, Nil};
+    let a = Rc::new(Cons(100, Rc::new(Cons(10, Rc::new(Nil))))); // Rc with 100 has reference count of 1
+    let b = Cons(3, Rc::clone(&a)); // Rc with 100 has reference count of 2, because it was cloned
+    let c = Cons(4, Rc::clone(&a)); // Rc with 100 has reference count of 3, because it was cloned again
+    println!("{}", Rc::strong_count(&a)); // 3
+    println!("{} {}", b.sum(), c.sum()); // 113 114
+}
+
+// Cell and RefCell
+// RefCell<T> enforcing borrowing rules at runtime
+// needed for Interior Mutability, can mutate data even when there are immutable references
+// when mutably borrowed, no more borrowers are allowed
+fn refcell() {
+    let c = RefCell::new(5);
+    {
+        let mut v = c.borrow_mut();
+        *v += 1;
+    }
+    println!("{c:?}"); // RefCell { value: 6 }
+}
+
+// RefCell and Rc can be combined
+// but only on one thread, neither is thread safe (Arc<Mutex<T>> in concurrency)
+mod shared {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[derive(Debug)]
+    pub enum List {
+        Cons(i32, Rc<RefCell<List>>), // a reference counting Rc contains the refcell which is holding the next list enum
+        Nil,
+    }
+}
+
+fn combined_rc_refcell() {
+    use shared::List::{Cons, Nil};
+    let list = Rc::new(RefCell::new(Cons(
+        5,
+        Rc::new(RefCell::new(Cons(10, Rc::new(RefCell::new(Nil))))),
+    )));
+    if let Cons(ref mut v, ref mut _r) = *list.borrow_mut() {
+        *v += 3;
+    }
+    println!("{:?}", list);
+}
+// Explanation by Bing Chat
+/*The borrow_mut method is called on the RefCell that wraps the list variable.
+This returns a mutable reference to the value inside the RefCell, which is the first element of the list.
+The * operator is used to dereference this mutable reference, giving us access to the first element of the list itself.
+The if let expression is used to pattern match on this first element of the list.
+The pattern used is Cons(ref mut v, ref mut _r), which matches against the Cons variant of the List enum.
+If the first element of the list is an instance of the Cons variant, then the values inside this variant are extracted and bound to the variables v and _r.
+The ref mut keywords are used to indicate that these variables should be mutable references to the values inside the variant, rather than copies of those values.
+Since v is a mutable reference to the first value inside the Cons variant, we can use it to modify this value directly.
+In this case, we add 3 to this value using the += operator.
+If the first element of the list is not an instance of the Cons variant, then nothing happens and control flow continues after the end of the if let expression. */
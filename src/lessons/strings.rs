@@ -0,0 +1,39 @@
+use super::{Demo, Lesson};
+
+pub const LESSON: Lesson = Lesson {
+    name: "strings",
+    source: include_str!("strings.rs"),
+    requires: &["collections"],
+    demos: &[Demo {
+        name: "string_basics",
+        run: string_basics,
+    }],
+};
+
+/*
+Struct String
+*/
+fn string_basics() {
+    // A String is basically a Vec<T>
+    let mut s = String::new();
+    println!("{}", s.len()); // 0, nothing in it yet
+    s = "something".to_string();
+    let a = String::from("Hello");
+
+    //append
+    s.push_str(" Yo");
+    s.push('u');
+    println!("s is {s}");
+
+    // concatenation
+    // let s3 = s + &a; // s is consumed
+    let s3 = format!("{s}-{a}"); // much nicer, takes references and doesnt consume
+    println!("{s3}");
+
+    // don't acces with [0] but with a range to create a slice
+    let b = &s3[0..4];
+    println!("{b}"); // "some"
+
+    let c: Vec<u8> = b.bytes().collect();
+    println!("{c:?}");
+}
@@ -0,0 +1,172 @@
+use super::{Demo, Lesson};
+
+pub const LESSON: Lesson = Lesson {
+    name: "structs_enums",
+    source: include_str!("structs_enums.rs"),
+    requires: &["ownership", "control_flow"],
+    demos: &[
+        Demo {
+            name: "this_struct",
+            run: this_struct,
+        },
+        Demo {
+            name: "tuple_struct",
+            run: tuple_struct,
+        },
+        Demo {
+            name: "this_enum",
+            run: this_enum,
+        },
+        Demo {
+            name: "options",
+            run: options,
+        },
+        Demo {
+            name: "realistic_options",
+            run: || {
+                println!(
+                    "{:?} {:?}",
+                    realistic_options(Some(41)),
+                    realistic_options(None)
+                )
+            },
+        },
+        Demo {
+            name: "really_short_option",
+            run: || {
+                println!(
+                    "{:?} {:?}",
+                    really_short_option(Some(41)),
+                    really_short_option(None)
+                )
+            },
+        },
+    ],
+};
+
+/*
+        Structs, Enums
+*/
+fn this_struct() {
+    // create own data types with Struct
+    struct Fish {
+        age: i32,
+        species: String,
+    }
+
+    // create Traits
+    trait Swimming {
+        fn swim(&self) {
+            println!("Swimming!");
+        }
+    }
+
+    // and implement them
+    impl Swimming for Fish {
+        fn swim(&self) {
+            println!("Swimming {}!", self.species);
+        }
+    }
+
+    let nemo = Fish {
+        age: 3,
+        species: String::from("Clownfish"),
+    };
+    println!("{} years old", nemo.age);
+    nemo.swim(); // Swimming Clownfish!
+}
+
+pub struct GnStruct(String); // Tuple Struct
+
+fn tuple_struct() {
+    let a = GnStruct("lol".to_string());
+    println!("{}", a.0); // lol, access tuple parameter
+}
+
+//      Enums
+fn this_enum() {
+    #[allow(dead_code)]
+    enum Store {
+        Online,
+        Local,
+        Brand,
+        PopUp,
+    }
+
+    let store: Store = Store::Online;
+    match store {
+        Store::Online => println!("Beep Boop"),
+        Store::Local => println!("Cash please!"),
+        _ => (), // Brand and PopUp have to be handled too, else the match is not exhaustive (compile-fail non_exhaustive_store)
+    }
+    // the retail lesson handles all four, each with its own payments, taxes and stock
+
+    // enums may hold values
+    #[allow(dead_code)]
+    enum Broker {
+        Neobroker { name: String },
+        BankDepot(String, i32),
+    }
+    let broker1: Broker = Broker::Neobroker {
+        name: String::from("Trade Republic"),
+    };
+    let broker2: Broker = Broker::BankDepot(String::from("Deutsche Bank"), 42);
+
+    // we can also define methods for enums
+    impl Broker {
+        fn buy_in() {
+            println!("Buy, Buy, Buy!")
+        }
+
+        fn sell_out(&self) {
+            // a match with only one interesting arm is shorter as if let
+            if let Broker::Neobroker { .. } = self {
+                println!("Sell!!!!")
+            }
+        }
+    }
+    Broker::buy_in(); // no self, so it's called on the type
+    broker1.sell_out(); // Sell!!
+    broker2.sell_out(); // the BankDepot does nothing
+
+    // the portfolio lesson gives them fees, an account and orders that really trade
+}
+
+//      Option<T>()
+// enum Option<T> {
+//    None,
+//   Some(T),
+//}
+
+fn options() {
+    let some_number = Some(5);
+    let mut some_char = Some('e');
+    let a = some_char.take(); // now a is owner of Some('e') and some_char is None
+    let absent_number: Option<i32> = None;
+    println!("{a:?} {some_char:?} {absent_number:?}");
+
+    println!("{}", some_number.is_none()); // false
+    println!("{}", some_number.is_some()); // true
+
+    // We cannot add Some(value) with value (compile-fail option_plus_value), we have to work on the value inside
+    let sum = some_number.map(|number| number + 4);
+    println!("{:?}", sum); // Some(9)
+}
+
+fn realistic_options(option: Option<i32>) -> Option<i32> {
+    match option {
+        Some(i) => {
+            // if the Option hold a value, return it incremented
+            println!("The number is {}", i);
+            Some(i + 1)
+        }
+        None => Some(0), // If the Option holds no value, return 0
+    }
+}
+
+fn really_short_option(option: Option<i32>) -> Option<i32> {
+    let a = option?; // this returns None instantly, if the Opion is not of kind Some(i)
+    Some(a + 1) //if it was Some(i) a is the value i and we can work with it, but we have to wrap it again (compile-fail question_mark_unwrapped)
+}
+
+// Result is also an enum, but treated in section about Errors
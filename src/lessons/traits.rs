@@ -0,0 +1,268 @@
+use std::any::Any;
+use std::fmt::{self, Debug, Display};
+use std::ops::Deref;
+
+use super::{Demo, Lesson};
+
+pub const LESSON: Lesson = Lesson {
+    name: "traits",
+    source: include_str!("traits.rs"),
+    requires: &["structs_enums"],
+    demos: &[
+        Demo {
+            name: "dynamic_dispatch",
+            run: dynamic_dispatch,
+        },
+        Demo {
+            name: "generic_highest",
+            run: || println!("{}", generic_highest(&[3, 42, 7])),
+        },
+        Demo {
+            name: "summary",
+            run: summary,
+        },
+        Demo {
+            name: "conditional_implementation",
+            run: conditional_implementation,
+        },
+        Demo {
+            name: "copy_clone_display",
+            run: copy_clone_display,
+        },
+        Demo {
+            name: "deref",
+            run: deref,
+        },
+    ],
+};
+
+/*
+Generics and Traits
+*/
+
+//      Polymorphism
+//      dyn-Keyword
+// Dynamic dispatch, have a varaible that has a specific type but declare it with dyn and an upper trait
+// static dispatch is faster btw
+fn log<T: Any + Debug>(value: &T) {
+    let value_any = value as &dyn Any;
+
+    match value_any.downcast_ref::<String>() {
+        Some(text) => println!("It's a string: {text}"),
+        None => println!("No String: {value:?}"),
+    }
+}
+
+// also possible with
+fn log_d(value_any: &dyn Any) -> &dyn Any {
+    value_any
+}
+
+fn dynamic_dispatch() {
+    log(&String::from("jeremias")); // It's a string: jeremias
+    log(&42); // No String: 42
+    println!("{}", log_d(&42).is::<i32>()); // true
+}
+
+//      Generic Type
+fn generic_highest<T: PartialOrd>(list: &[T]) -> &T {
+    let mut highest = &list[0];
+
+    for item in list {
+        if highest < item {
+            // compare the Types (they need to implement the comparison, without T: PartialOrd
+            // this doesn't compile, see compile-fail highest_without_bound)
+            highest = item;
+        }
+    }
+    highest
+}
+
+//      Traits
+pub trait Summary {
+    // basically an interface with possible default behaviour
+    fn summarize(&self) -> String {
+        // default implementation of the method
+        String::from("Read more here!")
+    }
+}
+
+pub struct Newspaper {
+    pub headline: String,
+    pub story: String,
+}
+
+impl Summary for Newspaper {
+    fn summarize(&self) -> String {
+        // implement a method from the trait specifically
+        self.headline.to_string()
+    }
+}
+
+impl Display for Newspaper {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.headline, self.story)
+    }
+}
+
+// this one keeps the default implementation
+#[derive(Clone)]
+pub struct Tweet;
+
+impl Summary for Tweet {}
+
+impl Display for Tweet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a tweet")
+    }
+}
+
+// Sub-traits, inheritance
+// if  a type implements Headline it must implement Summary and Display
+trait Headline: Summary + Display {}
+
+// as in Conditional Trait Implementation, you can use that for Inheritance
+// start a hierarchy where all types that impl Summary and Display have Headline...etc.
+impl<T: Summary + Display> Headline for T {}
+
+// Trait Bounds
+// this function needs item to implement Summary
+fn needs_trait_short(item: &impl Summary) {
+    println!("{}", item.summarize());
+}
+
+// useful if many items use this trait
+fn needs_trait<T: Summary>(item: &T, item2: &T) {
+    println!("{} {}", item.summarize(), item2.summarize());
+}
+
+// multiple traits
+fn needs_two_traits(item: &(impl Summary + Display)) {
+    println!("{item}");
+}
+fn needs_two_traits_too<T: Summary + Display>(item: &T) {
+    println!("{item}");
+}
+
+// can be more clear with:
+fn needs_two_traits_clear<T, U>(item: &T, item2: &U) -> i32
+where
+    // <T: Summary + Display, U: Summary + Clone>)
+    T: Summary + Display,
+    U: Summary + Clone,
+{
+    println!("{} {}", item.summarize(), item2.clone().summarize());
+    5
+}
+
+fn needs_a_headline(item: &dyn Headline) {
+    println!("{item} / {}", item.summarize());
+}
+
+// we of course can declare those in structs too!
+#[derive(Debug, PartialEq)]
+pub struct KeyLocker<T: Debug + PartialEq> {
+    name: T,
+}
+
+// returned Elements may implement a trait
+// WATCH OUT, you can only return a single type here, an if else with different types that implement the trait is not allowed
+fn returns_summarizable() -> impl Summary {
+    Newspaper {
+        headline: String::from("You won't believe it"),
+        story: String::from("Omg I like the game so much I have to.."),
+    }
+}
+
+fn summary() {
+    let paper = Newspaper {
+        headline: String::from("Rust 1.0 released"),
+        story: String::from("Finally stable."),
+    };
+    needs_trait_short(&paper); // Rust 1.0 released
+    needs_trait_short(&Tweet); // Read more here!
+    needs_trait(&Tweet, &Tweet);
+    needs_two_traits(&paper);
+    needs_two_traits_too(&paper);
+    println!("{}", needs_two_traits_clear(&paper, &Tweet)); // 5
+    needs_a_headline(&paper);
+    needs_trait_short(&returns_summarizable()); // You won't believe it
+
+    let locker = KeyLocker { name: "jeremias" };
+    println!("{:?} {}", locker, locker == KeyLocker { name: "jeremias" });
+}
+
+// Conditional Implementation
+
+struct Pair<T> {
+    x: T,
+    y: T,
+}
+
+impl<T> Pair<T> {
+    fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+// methods defined within this impl block will be available for any instance of Pair where the type parameter T implements both the Display and PartialOrd traits.
+impl<T: Display + PartialOrd> Pair<T> {
+    fn cmp_display(&self) {
+        if self.x >= self.y {
+            println!("The largest member is x = {}", self.x);
+        } else {
+            println!("The largest member is y = {}", self.y);
+        }
+    }
+}
+
+// std does the same: impl<T: Display> ToString for T, so everything with Display has .to_string()
+// we can't write that impl ourselves, neither the trait nor T are ours (compile-fail blanket_to_string)
+fn conditional_implementation() {
+    Pair::new(3, 7).cmp_display(); // The largest member is y = 7
+    Pair::new("b", "a").cmp_display(); // The largest member is x = b
+}
+
+// Copy,Clone,Display,Debug
+// COPY trait is for types whose values can be duplicated simply by copying bits.
+// is used by assignments like let x = y; and not explicitly
+// CLONE is type specific copy
+// specifies .clone()
+#[derive(Copy, Clone)]
+struct FooBar {
+    x: i32,
+    y: i32,
+}
+
+// Types that have Copy, must implement Clone (compile-fail copy_without_clone)
+// your own structs can't implement Copy, when they have items that don't implement Copy Trait
+
+// DISPLAY Trait
+// will also automatically implement the ToString trait, allowing .to_string
+impl fmt::Display for FooBar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+fn copy_clone_display() {
+    let a = FooBar { x: 1, y: 2 };
+    let b = a; // copied, a stays usable
+    println!("{a} {b}");
+    println!("{}", a.to_string().len()); // 6
+}
+
+// DEREF
+struct MyBox<T>(T);
+impl<T> Deref for MyBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0 // rust translate *y to *(y.deref)
+    }
+}
+
+fn deref() {
+    let x = 5;
+    let y = MyBox(x);
+    println!("{}", *y == x); // true
+}
@@ -0,0 +1,286 @@
+use std::slice;
+
+use super::{Demo, Lesson};
+
+pub const LESSON: Lesson = Lesson {
+    name: "unsafe_ffi",
+    source: include_str!("unsafe_ffi.rs"),
+    requires: &["smart_pointers", "traits"],
+    demos: &[
+        Demo {
+            name: "raw_pointers",
+            run: raw_pointers,
+        },
+        Demo {
+            name: "safe_abstraction",
+            run: safe_abstraction,
+        },
+        Demo {
+            name: "my_box",
+            run: my_box,
+        },
+        Demo {
+            name: "calling_c",
+            run: calling_c,
+        },
+    ],
+};
+
+/*
+Unsafe Rust and FFI
+*/
+// the compiler rejects every program it can't prove safe, sometimes a correct one too
+// unsafe { } allows five more things, nothing else changes (the borrow checker still runs):
+//  - dereferencing raw pointers
+//  - calling unsafe functions, every foreign function is one
+//  - using a static mut
+//  - implementing an unsafe trait (Send, Sync)
+//  - reading union fields
+// unsafe doesn't mean wrong, it means "I checked it, not the compiler". Every unsafe block
+// gets a SAFETY comment that says why the rules hold.
+// `localplayground miri` runs the parts without C under Miri, which finds undefined behaviour
+
+//      Raw pointers
+// *const T and *mut T: may be null, may dangle, may alias, no lifetimes
+// creating them is safe, only reading and writing through them is unsafe
+fn raw_pointers() {
+    let mut number = 5;
+    let write = &raw mut number;
+    let read = write as *const i32;
+    // SAFETY: both point to number, which lives until the end of the function,
+    // and no reference to number is used in between
+    unsafe {
+        *write += 1;
+        println!("{}", *read); // 6
+    }
+
+    let address = 0x012345usize;
+    let _nowhere = address as *const i32; // fine, as long as nobody reads it
+}
+
+//      Safe abstractions
+// the usual way: a small unsafe block inside a safe function, whose signature makes
+// wrong use impossible. split_at_mut from std is one: two &mut into one slice can't be
+// borrowed the normal way, even if they never overlap
+fn split_at_mut(values: &mut [i32], mid: usize) -> (&mut [i32], &mut [i32]) {
+    let len = values.len();
+    assert!(mid <= len); // this assert is what makes the unsafe block below sound
+    let start = values.as_mut_ptr();
+    // SAFETY: 0..mid and mid..len are inside the slice and don't overlap,
+    // both borrows live as long as the &mut we got
+    unsafe {
+        (
+            slice::from_raw_parts_mut(start, mid),
+            slice::from_raw_parts_mut(start.add(mid), len - mid),
+        )
+    }
+}
+
+fn safe_abstraction() {
+    let mut values = [1, 2, 3, 4, 5];
+    let (left, right) = split_at_mut(&mut values, 2);
+    left[0] = 10;
+    right[0] = 30;
+    println!("{values:?}"); // "[10, 2, 30, 4, 5]"
+}
+
+//      MyBox with manual allocation
+// the MyBox of traits::deref only wrapped the value, this one owns heap memory like Box:
+// allocate, write the value, read it through Deref, and drop and free it in Drop
+mod raw_box {
+    use std::alloc::{self, Layout};
+    use std::ops::{Deref, DerefMut};
+    use std::ptr::{self, NonNull};
+
+    pub struct MyBox<T> {
+        // NonNull: never null, and MyBox<T> stays covariant in T like Box<T>
+        pointer: NonNull<T>,
+    }
+
+    impl<T> MyBox<T> {
+        pub fn new(value: T) -> MyBox<T> {
+            let layout = Layout::new::<T>();
+            // allocating zero bytes is not allowed, a zero sized T needs no memory at all
+            let pointer = if layout.size() == 0 {
+                NonNull::dangling()
+            } else {
+                // SAFETY: the layout has a size > 0
+                let raw = unsafe { alloc::alloc(layout) } as *mut T;
+                NonNull::new(raw).unwrap_or_else(|| alloc::handle_alloc_error(layout))
+            };
+            // SAFETY: the memory is allocated for a T and not initialized yet, write
+            // doesn't drop the old "value" there like an assignment would
+            unsafe { pointer.as_ptr().write(value) };
+            MyBox { pointer }
+        }
+
+        pub fn into_inner(self) -> T {
+            // SAFETY: the value is initialized, read moves it out
+            let value = unsafe { self.pointer.as_ptr().read() };
+            // SAFETY: memory from new with the same layout, the value was moved out above
+            unsafe { free(self.pointer) };
+            // Drop would drop the value a second time
+            std::mem::forget(self);
+            value
+        }
+    }
+
+    // SAFETY: only frees, the caller makes sure the value was dropped or moved out
+    unsafe fn free<T>(pointer: NonNull<T>) {
+        let layout = Layout::new::<T>();
+        if layout.size() != 0 {
+            unsafe { alloc::dealloc(pointer.as_ptr() as *mut u8, layout) };
+        }
+    }
+
+    impl<T> Deref for MyBox<T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            // SAFETY: initialized in new, &self keeps MyBox and with it the value alive
+            unsafe { self.pointer.as_ref() }
+        }
+    }
+
+    impl<T> DerefMut for MyBox<T> {
+        fn deref_mut(&mut self) -> &mut T {
+            // SAFETY: like deref, and &mut self means nobody else has a reference
+            unsafe { self.pointer.as_mut() }
+        }
+    }
+
+    impl<T> Drop for MyBox<T> {
+        fn drop(&mut self) {
+            // SAFETY: the value is still there (into_inner forgets self), after
+            // drop_in_place nothing reads it anymore
+            unsafe {
+                ptr::drop_in_place(self.pointer.as_ptr());
+                free(self.pointer);
+            }
+        }
+    }
+
+    // a raw pointer is neither Send nor Sync, so MyBox isn't either until we say so
+    // SAFETY: MyBox owns its T like Box does, sending it sends the T
+    unsafe impl<T: Send> Send for MyBox<T> {}
+    // SAFETY: &MyBox<T> only hands out &T
+    unsafe impl<T: Sync> Sync for MyBox<T> {}
+}
+
+fn my_box() {
+    use raw_box::MyBox;
+    let mut text = MyBox::new(String::from("on the heap"));
+    text.push_str(", twice"); // DerefMut, and a reallocation of the String inside
+    println!("{}", *text); // "on the heap, twice"
+    println!("{}", text.len()); // 18
+    let number = MyBox::new(5);
+    println!("{}", *number == 5); // true, like traits::deref
+    let unit = MyBox::new(()); // zero sized, nothing allocated
+    println!("{:?}", unit.into_inner()); // ()
+}
+
+//      FFI
+// extern "C" declares functions of another language with the C calling convention,
+// build.rs compiles src/lessons/c/playground.c and links it in
+// Rust can't check anything the C code does, so every call is unsafe
+// the declarations are repeated by hand, they must match the C side exactly
+mod c_lib {
+    use std::alloc::{self, Layout};
+    use std::ffi::{c_char, CStr, CString, NulError};
+    use std::ptr::NonNull;
+
+    // the raw C functions, only used in this module
+    mod sys {
+        use std::ffi::c_char;
+
+        // only ever behind a pointer, Rust doesn't know its fields or size
+        #[repr(C)]
+        pub struct Counter {
+            _private: [u8; 0],
+        }
+
+        extern "C" {
+            pub fn pg_add(a: i32, b: i32) -> i64;
+            pub fn pg_sum(values: *const i32, len: usize) -> i64;
+            pub fn pg_counter_new() -> *mut Counter;
+            pub fn pg_counter_increment(counter: *mut Counter) -> u32;
+            pub fn pg_counter_free(counter: *mut Counter);
+            pub fn pg_greet(name: *const c_char, out: *mut c_char, len: usize) -> usize;
+        }
+    }
+
+    // the safe API: slices, Strings and a type that frees itself
+
+    // with i32 as the result add(i32::MAX, 1) would overflow in C, and that is undefined
+    // behaviour there, not a panic or a wrap. A safe function must not allow that for any
+    // input, so C adds in 64 bits
+    pub fn add(a: i32, b: i32) -> i64 {
+        // SAFETY: plain values in and out, the sum of two i32 always fits into an i64
+        unsafe { sys::pg_add(a, b) }
+    }
+
+    pub fn sum(values: &[i32]) -> i64 {
+        // SAFETY: pointer and length come from the same slice, C only reads
+        unsafe { sys::pg_sum(values.as_ptr(), values.len()) }
+    }
+
+    pub struct Counter {
+        raw: NonNull<sys::Counter>,
+    }
+
+    impl Counter {
+        pub fn new() -> Counter {
+            // SAFETY: no arguments, the result is checked for null
+            let raw = unsafe { sys::pg_counter_new() };
+            // out of memory, like a Box would: the C struct holds one uint32_t
+            let raw = NonNull::new(raw)
+                .unwrap_or_else(|| alloc::handle_alloc_error(Layout::new::<u32>()));
+            Counter { raw }
+        }
+
+        // &mut self: C changes the counter, so two calls at once must be impossible
+        pub fn increment(&mut self) -> u32 {
+            // SAFETY: raw is a live counter from pg_counter_new
+            unsafe { sys::pg_counter_increment(self.raw.as_ptr()) }
+        }
+    }
+
+    impl Drop for Counter {
+        fn drop(&mut self) {
+            // SAFETY: freed exactly once, Counter can't be cloned
+            unsafe { sys::pg_counter_free(self.raw.as_ptr()) }
+        }
+    }
+
+    // Rust strings have a length, C strings end with a 0 byte, CString and CStr convert
+    pub fn greet(name: &str) -> Result<String, NulError> {
+        // a 0 in the middle would end the C string early, CString refuses it
+        let name = CString::new(name)?;
+        let mut out = vec![0 as c_char; 8];
+        loop {
+            // SAFETY: name ends with 0, out has out.len() writable bytes
+            let needed = unsafe { sys::pg_greet(name.as_ptr(), out.as_mut_ptr(), out.len()) };
+            if needed <= out.len() {
+                break;
+            }
+            // too small, C tells us how much it needs, like snprintf
+            out = vec![0; needed];
+        }
+        // SAFETY: pg_greet always ends what it wrote with a 0
+        let greeting = unsafe { CStr::from_ptr(out.as_ptr()) };
+        Ok(greeting.to_string_lossy().into_owned())
+    }
+}
+
+fn calling_c() {
+    println!("{}", c_lib::add(40, 2)); // 42
+    println!("{}", c_lib::add(i32::MAX, 1)); // 2147483648
+    println!("{}", c_lib::sum(&[1, 2, 3, 4])); // 10
+    let mut counter = c_lib::Counter::new();
+    counter.increment();
+    println!("{}", counter.increment()); // 2
+    if let Ok(greeting) = c_lib::greet("Ferris") {
+        println!("{greeting}"); // "Hello, Ferris!"
+    }
+    println!("{}", c_lib::greet("Fer\0ris").is_err()); // true, C would only see "Fer"
+}
@@ -1,3 +1,169 @@
-fn main() {
-    print!("hELLO World");
-} 
\ No newline at end of file
+mod book;
+mod compile_fail;
+mod coverage;
+mod exercises;
+mod expand;
+mod explain;
+mod i18n;
+mod json;
+mod lessons;
+mod miri;
+mod path;
+mod progress;
+mod quiz;
+mod repl;
+mod reviews;
+mod sandbox;
+mod search;
+mod source;
+mod toolchain;
+mod verify;
+mod watch;
+
+use std::env;
+use std::panic;
+use std::process::ExitCode;
+
+use lessons::{Demo, Lesson, LESSONS};
+
+const USAGE: &str = "usage: localplayground [--lang de|en] <command>
+
+commands:
+    list                      show all lessons and their demos
+    run <lesson> [demo]       run every demo of a lesson, or just one
+    run --all                 run every lesson
+    compile-fail [snippet]    check that the broken examples still fail
+                              with the expected rustc error (--show prints them)
+    verify [lesson]           compare the `// output` comments with what the demos print
+    i18n                      report lesson texts missing in German or English
+    repl                      try out snippets, compiled with the local rustc
+    exercises                 list the exercises
+    start <exercise>          write the starter file to exercises/<exercise>.rs
+    check <exercise> [file]   run the hidden tests against your solution
+    expand <lesson|file.rs> [function]
+                              show the code after macro expansion (rustc -Zunpretty=expanded)
+    watch <lesson|exercise>   re-run a lesson or check an exercise whenever it is saved
+    explain <file.rs|EXXXX>   explain rustc's errors with the lesson that covers them
+    progress                  show which lessons and exercises you have done
+    reset <lesson>            forget the progress of a lesson to do it again
+    path [lesson]             the order to learn in and which lessons you're ready for
+    search <query> [--all]    find where the lessons explain or use something
+    miri [--show]             check the unsafe code of unsafe_ffi with Miri, if it is installed
+    coverage [module]         which core std APIs the lessons teach (--where shows where)
+    reviews list|show|add|rm  keep book reviews in the data dir (`reviews help` for more)
+    book <dir> [--markdown]   export the lessons as a static HTML site (or Markdown)
+    quiz [lesson]             answer questions about the lessons (--seed n for a fixed order,
+                              --check compares the answers with rustc)
+
+without --lang the language is taken from LANG";
+
+fn main() -> ExitCode {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let lang = take_option(&mut args, "--lang");
+    if let Err(message) = i18n::init(lang.as_deref()) {
+        eprintln!("error: {message}");
+        return ExitCode::FAILURE;
+    }
+    let result = match args.first().map(String::as_str) {
+        Some("list") => list(),
+        Some("run") => run(&args[1..]),
+        Some("compile-fail") => compile_fail::command(&args[1..]),
+        Some("verify") => verify::command(&args[1..]),
+        Some("i18n") => i18n::command(&args[1..]),
+        Some("repl") => repl::command(&args[1..]),
+        Some("exercises") => exercises::list(&args[1..]),
+        Some("start") => exercises::start(&args[1..]),
+        Some("check") => exercises::command(&args[1..]),
+        Some("expand") => expand::command(&args[1..]),
+        Some("watch") => watch::command(&args[1..]),
+        Some("explain") => explain::command(&args[1..]),
+        Some("progress") => progress::command(&args[1..]),
+        Some("reset") => progress::reset(&args[1..]),
+        Some("path") => path::command(&args[1..]),
+        Some("search") => search::command(&args[1..]),
+        Some("miri") => miri::command(&args[1..]),
+        Some("coverage") => coverage::command(&args[1..]),
+        Some("reviews") => reviews::command(&args[1..]),
+        Some("book") => book::command(&args[1..]),
+        Some("quiz") => quiz::command(&args[1..]),
+        _ => {
+            println!("{USAGE}");
+            Ok(())
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// removes `--name value` from the arguments, wherever it is
+fn take_option(args: &mut Vec<String>, name: &str) -> Option<String> {
+    let index = args.iter().position(|arg| arg == name)?;
+    args.remove(index);
+    (index < args.len()).then(|| args.remove(index))
+}
+
+fn list() -> Result<(), String> {
+    for lesson in LESSONS {
+        println!("{:<16} {}", lesson.name, lesson.title());
+        for demo in lesson.demos {
+            println!("    {}", demo.name);
+        }
+    }
+    Ok(())
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let mut failed = 0;
+    match args {
+        [all] if all == "--all" => {
+            for lesson in LESSONS {
+                failed += run_lesson(lesson);
+            }
+        }
+        [name] => failed += run_lesson(find_lesson(name)?),
+        [name, demo] => {
+            let lesson = find_lesson(name)?;
+            let demo = lesson
+                .demo(demo)
+                .ok_or_else(|| format!("lesson `{name}` has no demo `{demo}`"))?;
+            failed += run_demo(lesson, demo);
+        }
+        _ => return Err("usage: run <lesson> [demo] | run --all".to_string()),
+    }
+    // the demos that panic on purpose are not counted, any other panic is a bug in a lesson
+    match failed {
+        0 => Ok(()),
+        n => Err(format!("{n} demo(s) panicked")),
+    }
+}
+
+fn find_lesson(name: &str) -> Result<&'static Lesson, String> {
+    lessons::find(name)
+        .ok_or_else(|| format!("unknown lesson `{name}`, see `localplayground list`"))
+}
+
+fn run_lesson(lesson: &Lesson) -> usize {
+    println!("==== {} ====", lesson.title());
+    for paragraph in lesson.intro() {
+        println!("{paragraph}\n");
+    }
+    let failed = lesson.demos.iter().map(|demo| run_demo(lesson, demo)).sum();
+    // having gone through all demos counts as done, even the ones panicking on purpose
+    progress::record_or_warn(progress::Kind::Lesson, lesson.name, true);
+    failed
+}
+
+// runs a single demo, a panic only ends the demo and not the whole playground
+fn run_demo(lesson: &Lesson, demo: &Demo) -> usize {
+    println!("---- {} ----", demo.name);
+    match panic::catch_unwind(demo.run) {
+        Ok(()) => 0,
+        Err(_) if lesson.panics_on_purpose(demo) => 0,
+        Err(_) => 1,
+    }
+}
@@ -0,0 +1,107 @@
+/*
+    Miri
+*/
+// The unsafe_ffi lesson claims that its unsafe blocks are sound. Miri, an interpreter for
+// Rust's mid-level IR, checks claims like that while it runs the code: out of bounds
+// accesses, use after free, double frees, broken aliasing rules and leaks.
+// `localplayground miri` copies the parts of the lesson that don't call C (Miri can't run
+// foreign code) into a scratch crate and runs it with `cargo miri run`.
+// Miri is a nightly component, if it isn't installed we say how to get it and stop there.
+
+use std::fs;
+use std::process::Command;
+
+use crate::lessons;
+use crate::source;
+use crate::toolchain::Scratch;
+
+const LESSON: &str = "unsafe_ffi";
+const MODULES: &[&str] = &["raw_box"];
+// helpers first, the demos are called from main in this order
+const FUNCTIONS: &[&str] = &["split_at_mut"];
+const DEMOS: &[&str] = &["raw_pointers", "safe_abstraction", "my_box"];
+
+// `cargo miri` on the default toolchain, otherwise on nightly
+fn cargo_miri() -> Option<Vec<&'static str>> {
+    [vec!["miri"], vec!["+nightly", "miri"]]
+        .into_iter()
+        .find(|args| {
+            Command::new("cargo")
+                .args(args)
+                .arg("--version")
+                .output()
+                .is_ok_and(|output| output.status.success())
+        })
+}
+
+fn program() -> Result<String, String> {
+    let lesson = lessons::find(LESSON).ok_or("the unsafe_ffi lesson is missing")?;
+    let mut program = String::new();
+    for line in lesson.source.lines() {
+        if line.starts_with("use std::") {
+            program.push_str(line);
+            program.push('\n');
+        }
+    }
+    for name in MODULES {
+        let module = source::module(lesson.source, name)
+            .ok_or_else(|| format!("{LESSON} has no module `{name}`"))?;
+        program.push_str(&format!("\n{}\n", module.join("\n")));
+    }
+    let functions = source::functions(lesson.source);
+    for name in FUNCTIONS.iter().chain(DEMOS) {
+        let function = functions
+            .iter()
+            .find(|function| function.name == *name)
+            .ok_or_else(|| format!("{LESSON} has no function `{name}`"))?;
+        let lines: Vec<&str> = function.lines.iter().map(|(_, line)| *line).collect();
+        program.push_str(&format!("\n{}\n", lines.join("\n")));
+    }
+    program.push_str("\nfn main() {\n");
+    for name in DEMOS {
+        program.push_str(&format!(
+            "    println!(\"---- {name} ----\");\n    {name}();\n"
+        ));
+    }
+    program.push_str("}\n");
+    Ok(program)
+}
+
+// miri [--show]
+pub fn command(args: &[String]) -> Result<(), String> {
+    let program = program()?;
+    if args.iter().any(|arg| arg == "--show") {
+        print!("{program}");
+        return Ok(());
+    }
+    let Some(miri) = cargo_miri() else {
+        println!("Miri is not installed, nothing was checked.");
+        println!("install it with: rustup +nightly component add miri");
+        return Ok(());
+    };
+
+    let scratch = Scratch::new("miri")?;
+    scratch.write(
+        "Cargo.toml",
+        "[package]\nname = \"miri-check\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[workspace]\n",
+    )?;
+    let src = scratch.path().join("src");
+    fs::create_dir_all(&src).map_err(|e| format!("cannot create {}: {e}", src.display()))?;
+    let main = src.join("main.rs");
+    fs::write(&main, program).map_err(|e| format!("cannot write {}: {e}", main.display()))?;
+
+    println!("running {} under Miri: {}", LESSON, DEMOS.join(", "));
+    let status = Command::new("cargo")
+        .args(miri)
+        .args(["run", "--quiet", "--manifest-path"])
+        .arg(scratch.path().join("Cargo.toml"))
+        .status()
+        .map_err(|e| format!("cannot run cargo miri: {e}"))?;
+    if !status.success() {
+        return Err(
+            "Miri found undefined behaviour, or the crate didn't build, see above".to_string(),
+        );
+    }
+    println!("Miri found no undefined behaviour");
+    Ok(())
+}
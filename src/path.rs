@@ -0,0 +1,148 @@
+/*
+    Learning path
+*/
+// Lessons name the lessons they build on (`requires` in their registration).
+// `localplayground path` puts them into an order where every lesson comes after its
+// prerequisites, as close to the reading order as possible, and marks which ones the
+// learner has done and which ones they are ready for. `path <lesson>` only shows
+// what leads to that lesson.
+// Unknown names and cycles in the prerequisites are reported instead of a path.
+
+use crate::lessons::{self, Lesson, LESSONS};
+use crate::progress;
+
+// everything wrong with the declared prerequisites, empty if they are fine
+pub fn validate() -> Vec<String> {
+    let mut problems = Vec::new();
+    for lesson in LESSONS {
+        for required in lesson.requires {
+            if lessons::find(required).is_none() {
+                problems.push(format!(
+                    "{} requires `{required}`, which is no lesson",
+                    lesson.name
+                ));
+            }
+        }
+    }
+    // a depth first search finds the cycles, `stack` is the way we came
+    fn visit(
+        lesson: &'static Lesson,
+        stack: &mut Vec<&'static str>,
+        finished: &mut Vec<&'static str>,
+        problems: &mut Vec<String>,
+    ) {
+        if finished.contains(&lesson.name) {
+            return;
+        }
+        if let Some(start) = stack.iter().position(|name| *name == lesson.name) {
+            let mut cycle = stack[start..].to_vec();
+            cycle.push(lesson.name);
+            problems.push(format!("cycle: {}", cycle.join(" -> ")));
+            return;
+        }
+        stack.push(lesson.name);
+        for required in lesson
+            .requires
+            .iter()
+            .filter_map(|name| lessons::find(name))
+        {
+            visit(required, stack, finished, problems);
+        }
+        stack.pop();
+        finished.push(lesson.name);
+    }
+    let mut finished = Vec::new();
+    for lesson in LESSONS {
+        visit(lesson, &mut Vec::new(), &mut finished, &mut problems);
+    }
+    problems
+}
+
+// prerequisites first, otherwise in reading order
+pub fn order() -> Result<Vec<&'static Lesson>, String> {
+    let problems = validate();
+    if !problems.is_empty() {
+        return Err(format!(
+            "the lesson prerequisites are broken:\n    {}",
+            problems.join("\n    ")
+        ));
+    }
+    let mut ordered: Vec<&'static Lesson> = Vec::new();
+    while ordered.len() < LESSONS.len() {
+        let next = LESSONS
+            .iter()
+            .find(|lesson| {
+                !ordered.iter().any(|done| done.name == lesson.name)
+                    && lesson
+                        .requires
+                        .iter()
+                        .all(|required| ordered.iter().any(|done| done.name == *required))
+            })
+            .ok_or("the lesson prerequisites have a cycle")?;
+        ordered.push(next);
+    }
+    Ok(ordered)
+}
+
+// the lesson and everything it builds on, directly or not
+fn leading_to(lesson: &'static Lesson) -> Vec<&'static str> {
+    let mut names = vec![lesson.name];
+    let mut index = 0;
+    while let Some(name) = names.get(index) {
+        let requires = lessons::find(name).map_or(&[][..], |lesson| lesson.requires);
+        for required in requires {
+            if !names.contains(required) {
+                names.push(required);
+            }
+        }
+        index += 1;
+    }
+    names
+}
+
+// path [lesson]
+pub fn command(args: &[String]) -> Result<(), String> {
+    let mut ordered = order()?;
+    if let Some(name) = args.first() {
+        let lesson = lessons::find(name)
+            .ok_or_else(|| format!("unknown lesson `{name}`, see `localplayground list`"))?;
+        let wanted = leading_to(lesson);
+        ordered.retain(|lesson| wanted.contains(&lesson.name));
+    }
+    let done = progress::lessons_done()?;
+    let is_done = |name: &str| done.iter().any(|done| done == name);
+
+    println!("    {:<16} {:<8} needs", "lesson", "status");
+    let mut ready = Vec::new();
+    for (number, lesson) in ordered.iter().enumerate() {
+        let missing: Vec<&str> = lesson
+            .requires
+            .iter()
+            .copied()
+            .filter(|required| !is_done(required))
+            .collect();
+        let status = if is_done(lesson.name) {
+            "done"
+        } else if missing.is_empty() {
+            ready.push(lesson.name);
+            "ready"
+        } else {
+            "waiting"
+        };
+        let row = format!(
+            "{:>2}  {:<16} {status:<8} {}",
+            number + 1,
+            lesson.name,
+            missing.join(", ")
+        );
+        println!("{}", row.trim_end());
+    }
+    match ready.first() {
+        Some(next) => println!(
+            "ready for: {}\nnext up: localplayground run {next}",
+            ready.join(", ")
+        ),
+        None => println!("all done"),
+    }
+    Ok(())
+}
@@ -0,0 +1,250 @@
+/*
+    Learner progress
+*/
+// What the learner has done so far is kept in progress.txt in the data directory
+// ($XDG_DATA_HOME/localplayground, or ~/.local/share/localplayground), one record per line:
+//     1760781234 lesson ownership done
+//     1760781301 exercise longest fail
+// `run <lesson>` marks a lesson as done, every `check <exercise>` is an attempt.
+// `localplayground progress` shows the table, `reset <lesson>` forgets a lesson again.
+
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::exercises::EXERCISES;
+use crate::lessons::{self, LESSONS};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Kind {
+    Lesson,
+    Exercise,
+}
+
+impl Kind {
+    fn word(self) -> &'static str {
+        match self {
+            Kind::Lesson => "lesson",
+            Kind::Exercise => "exercise",
+        }
+    }
+}
+
+pub struct Record {
+    // seconds since the unix epoch
+    pub time: u64,
+    pub kind: Kind,
+    pub name: String,
+    pub passed: bool,
+}
+
+impl Record {
+    fn parse(line: &str) -> Option<Record> {
+        let mut fields = line.split_whitespace();
+        let time = fields.next()?.parse().ok()?;
+        let kind = match fields.next()? {
+            "lesson" => Kind::Lesson,
+            "exercise" => Kind::Exercise,
+            _ => return None,
+        };
+        let name = fields.next()?.to_string();
+        let passed = match fields.next()? {
+            "done" | "pass" => true,
+            "fail" => false,
+            _ => return None,
+        };
+        Some(Record {
+            time,
+            kind,
+            name,
+            passed,
+        })
+    }
+
+    fn line(&self) -> String {
+        let result = match (self.kind, self.passed) {
+            (Kind::Lesson, _) => "done",
+            (Kind::Exercise, true) => "pass",
+            (Kind::Exercise, false) => "fail",
+        };
+        format!(
+            "{} {} {} {result}\n",
+            self.time,
+            self.kind.word(),
+            self.name
+        )
+    }
+}
+
+// $XDG_DATA_HOME/localplayground, the reviews are kept there too
+pub fn data_dir() -> Result<PathBuf, String> {
+    let data = match env::var_os("XDG_DATA_HOME").filter(|dir| !dir.is_empty()) {
+        Some(dir) => PathBuf::from(dir),
+        None => {
+            let home = env::var_os("HOME").ok_or("neither XDG_DATA_HOME nor HOME is set")?;
+            PathBuf::from(home).join(".local").join("share")
+        }
+    };
+    Ok(data.join("localplayground"))
+}
+
+fn path() -> Result<PathBuf, String> {
+    Ok(data_dir()?.join("progress.txt"))
+}
+
+// all records, oldest first, nothing done yet is an empty store
+pub fn load() -> Result<Vec<Record>, String> {
+    let path = path()?;
+    match fs::read_to_string(&path) {
+        // lines we don't understand (from a newer version maybe) are skipped
+        Ok(contents) => Ok(contents.lines().filter_map(Record::parse).collect()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(format!("cannot read {}: {e}", path.display())),
+    }
+}
+
+// names of the lessons that ran at least once
+pub fn lessons_done() -> Result<Vec<String>, String> {
+    Ok(load()?
+        .into_iter()
+        .filter(|record| record.kind == Kind::Lesson)
+        .map(|record| record.name)
+        .collect())
+}
+
+pub fn record(kind: Kind, name: &str, passed: bool) -> Result<(), String> {
+    let path = path()?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("cannot create {}: {e}", dir.display()))?;
+    }
+    let record = Record {
+        time: now(),
+        kind,
+        name: name.to_string(),
+        passed,
+    };
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| file.write_all(record.line().as_bytes()))
+        .map_err(|e| format!("cannot write {}: {e}", path.display()))
+}
+
+// for the commands that only record on the side: losing progress shouldn't stop them
+pub fn record_or_warn(kind: Kind, name: &str, passed: bool) {
+    if let Err(message) = record(kind, name, passed) {
+        eprintln!("warning: progress not saved, {message}");
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|since| since.as_secs())
+        .unwrap_or(0)
+}
+
+// 2026-10-18 14:05 (UTC), without pulling in a date crate
+pub fn format_time(time: u64) -> String {
+    let days = (time / 86400) as i64;
+    let minutes = time % 86400 / 60;
+    // civil_from_days by Howard Hinnant
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year}-{month:02}-{day:02} {:02}:{:02}",
+        minutes / 60,
+        minutes % 60
+    )
+}
+
+// progress: one row per lesson, in reading order
+pub fn command(_args: &[String]) -> Result<(), String> {
+    let records = load()?;
+    println!(
+        "{:<16} {:<6} {:<11} {:<9} last activity",
+        "lesson", "done", "exercises", "attempts"
+    );
+    let mut finished = 0;
+    for lesson in LESSONS {
+        let exercises: Vec<&str> = EXERCISES
+            .iter()
+            .filter(|exercise| exercise.lesson == lesson.name)
+            .map(|exercise| exercise.name)
+            .collect();
+        let belongs = |record: &&Record| match record.kind {
+            Kind::Lesson => record.name == lesson.name,
+            Kind::Exercise => exercises.contains(&record.name.as_str()),
+        };
+        let done = records
+            .iter()
+            .any(|record| record.kind == Kind::Lesson && record.name == lesson.name);
+        let solved = exercises
+            .iter()
+            .filter(|&&name| {
+                records.iter().any(|record| {
+                    record.kind == Kind::Exercise && record.name == name && record.passed
+                })
+            })
+            .count();
+        let attempts = records
+            .iter()
+            .filter(|record| record.kind == Kind::Exercise && belongs(record))
+            .count();
+        let last = records
+            .iter()
+            .filter(belongs)
+            .map(|record| record.time)
+            .max();
+
+        // a lesson is finished once it ran and all of its exercises passed
+        if done && solved == exercises.len() {
+            finished += 1;
+        }
+        println!(
+            "{:<16} {:<6} {:<11} {:<9} {}",
+            lesson.name,
+            if done { "yes" } else { "-" },
+            format!("{solved}/{}", exercises.len()),
+            attempts,
+            last.map(format_time).unwrap_or_else(|| "-".to_string())
+        );
+    }
+    println!("{finished}/{} lessons finished", LESSONS.len());
+    Ok(())
+}
+
+// reset <lesson>: forgets that the lesson ran and all attempts at its exercises
+pub fn reset(args: &[String]) -> Result<(), String> {
+    let name = args.first().ok_or("usage: reset <lesson>")?;
+    let lesson = lessons::find(name)
+        .ok_or_else(|| format!("unknown lesson `{name}`, see `localplayground list`"))?;
+    let belongs = |record: &Record| match record.kind {
+        Kind::Lesson => record.name == lesson.name,
+        Kind::Exercise => EXERCISES
+            .iter()
+            .any(|exercise| exercise.name == record.name && exercise.lesson == lesson.name),
+    };
+
+    let records = load()?;
+    let (removed, kept): (Vec<Record>, Vec<Record>) = records.into_iter().partition(belongs);
+    if removed.is_empty() {
+        println!("no progress recorded for {}", lesson.name);
+        return Ok(());
+    }
+    let path = path()?;
+    let contents: String = kept.iter().map(Record::line).collect();
+    fs::write(&path, contents).map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+    println!("forgot {} record(s) of {}", removed.len(), lesson.name);
+    Ok(())
+}
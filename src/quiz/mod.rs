@@ -0,0 +1,373 @@
+/*
+    Quiz
+*/
+// Questions about the lessons, from questions.txt next to this file. There are three kinds:
+// multiple choice, predicting what a program prints and spotting the line rustc rejects.
+// The last kind shows the compile-fail snippets, so the quiz and the harness share them.
+// `localplayground quiz [lesson]` asks them in random order, `quiz --check` makes sure
+// the printed lines and line numbers in the bank are still what rustc and the programs say.
+
+use std::io::{self, BufRead, Write};
+use std::process::{self, Command};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::compile_fail::{self, Snippet};
+use crate::lessons;
+use crate::sandbox::{self, Limits};
+use crate::source;
+use crate::toolchain::{self, Scratch};
+
+const BANK: &str = include_str!("questions.txt");
+
+pub enum Kind {
+    // the options and the index of the right one
+    Choice(Vec<String>, usize),
+    // the line the program prints
+    Output(String),
+    // the snippet and the line rustc reports first, counted from 1
+    ErrorLine(&'static Snippet, usize),
+}
+
+pub struct Question {
+    pub kind: Kind,
+    // lesson::function
+    pub topic: String,
+    pub ask: String,
+    pub code: String,
+    pub explain: Vec<String>,
+    // where the question starts in questions.txt
+    pub line: usize,
+}
+
+impl Question {
+    fn lesson(&self) -> &str {
+        self.topic.split("::").next().unwrap_or_default()
+    }
+
+    // the code as the learner sees it, error_line questions get line numbers
+    // and lose their comments, those tend to give the answer away
+    fn shown_code(&self) -> String {
+        match &self.kind {
+            Kind::ErrorLine(snippet, _) => snippet
+                .code
+                .lines()
+                .enumerate()
+                .map(|(i, line)| {
+                    let shown = format!("{:>3} | {}", i + 1, source::code(line));
+                    format!("{}\n", shown.trim_end())
+                })
+                .collect(),
+            _ => self
+                .code
+                .lines()
+                .map(|line| format!("    {line}\n"))
+                .collect(),
+        }
+    }
+
+    // `ownership::copy_trait (src/lessons/ownership.rs:101)`
+    fn link(&self) -> String {
+        let (lesson, function) = self.topic.split_once("::").unwrap_or((&self.topic, ""));
+        let line = lessons::find(lesson).and_then(|lesson| {
+            source::functions(lesson.source)
+                .into_iter()
+                .find(|f| f.name == function)
+                .and_then(|f| f.lines.first().map(|&(number, _)| number))
+        });
+        match line {
+            Some(line) => format!("{} (src/lessons/{lesson}.rs:{line})", self.topic),
+            None => self.topic.clone(),
+        }
+    }
+}
+
+// parses the bank, the format is described at the top of questions.txt
+pub fn questions() -> Result<Vec<Question>, String> {
+    let mut questions = Vec::new();
+    let mut block: Vec<(usize, &str)> = Vec::new();
+    // the empty line at the end finishes the last block
+    for (index, line) in BANK.lines().chain([""]).enumerate() {
+        if line.starts_with('#') {
+            continue;
+        }
+        if line.trim().is_empty() {
+            if !block.is_empty() {
+                questions.push(parse_question(&block)?);
+                block.clear();
+            }
+            continue;
+        }
+        block.push((index + 1, line));
+    }
+    Ok(questions)
+}
+
+fn parse_question(block: &[(usize, &str)]) -> Result<Question, String> {
+    let start = block[0].0;
+    let error = |message: String| format!("questions.txt:{start}: {message}");
+    let (mut kind, mut topic, mut ask, mut answer, mut snippet) = ("", "", "", "", "");
+    let mut code = String::new();
+    let mut options = Vec::new();
+    let mut right = Vec::new();
+    let mut explain = Vec::new();
+
+    for &(number, line) in block {
+        if let Some(rest) = line.strip_prefix('|') {
+            code.push_str(rest.strip_prefix(' ').unwrap_or(rest));
+            code.push('\n');
+        } else if let Some(option) = line.strip_prefix("- ") {
+            options.push(option.to_string());
+        } else if let Some(option) = line.strip_prefix("* ") {
+            right.push(options.len());
+            options.push(option.to_string());
+        } else if let Some((key, value)) = line.split_once('=') {
+            let value = value.trim();
+            match key.trim() {
+                "kind" => kind = value,
+                "topic" => topic = value,
+                "ask" => ask = value,
+                "answer" => answer = value,
+                "snippet" => snippet = value,
+                "explain" => explain.push(value.to_string()),
+                other => return Err(format!("questions.txt:{number}: unknown key `{other}`")),
+            }
+        } else {
+            return Err(format!("questions.txt:{number}: cannot read `{line}`"));
+        }
+    }
+
+    let kind = match kind {
+        "choice" => match right[..] {
+            [index] if options.len() > 1 => Kind::Choice(options, index),
+            _ => {
+                return Err(error(
+                    "a choice needs options and exactly one right one (*)".to_string(),
+                ))
+            }
+        },
+        "output" if !answer.is_empty() => Kind::Output(answer.to_string()),
+        "error_line" => {
+            let snippet = compile_fail::find(snippet)
+                .ok_or_else(|| error(format!("unknown snippet `{snippet}`")))?;
+            let line = answer
+                .parse()
+                .map_err(|_| error(format!("`{answer}` is no line number")))?;
+            Kind::ErrorLine(snippet, line)
+        }
+        _ => return Err(error(format!("`{kind}` questions need an answer"))),
+    };
+    if topic.is_empty() || ask.is_empty() {
+        return Err(error("topic or ask is missing".to_string()));
+    }
+    Ok(Question {
+        kind,
+        topic: topic.to_string(),
+        ask: ask.to_string(),
+        code,
+        explain,
+        line: start,
+    })
+}
+
+// xorshift64, good enough to shuffle a handful of questions
+struct Random(u64);
+
+impl Random {
+    fn from_time() -> Random {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|since| since.as_nanos() as u64)
+            .unwrap_or(0);
+        Random::new(nanos ^ u64::from(process::id()))
+    }
+
+    fn new(seed: u64) -> Random {
+        // xorshift gets stuck at 0
+        Random(seed.max(1))
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = (self.next() % (i as u64 + 1)) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+// mixes the options, so the right one isn't always in the same place
+fn shuffle_options(question: &mut Question, random: &mut Random) {
+    if let Kind::Choice(options, right) = &mut question.kind {
+        let mut order: Vec<usize> = (0..options.len()).collect();
+        random.shuffle(&mut order);
+        *right = order.iter().position(|&i| i == *right).unwrap_or_default();
+        *options = order.iter().map(|&i| options[i].clone()).collect();
+    }
+}
+
+// whitespace doesn't count for the printed line
+fn same_output(given: &str, expected: &str) -> bool {
+    let words = |text: &str| text.split_whitespace().collect::<Vec<_>>().join(" ");
+    words(given) == words(expected)
+}
+
+fn ask(question: &Question, number: usize, total: usize) {
+    let kind = match question.kind {
+        Kind::Choice(..) => "multiple choice",
+        Kind::Output(_) => "predict the output",
+        Kind::ErrorLine(..) => "spot the error",
+    };
+    println!("\n[{number}/{total}] {kind}\n{}\n", question.ask);
+    print!("{}", question.shown_code());
+    if let Kind::Choice(options, _) = &question.kind {
+        println!();
+        for (i, option) in options.iter().enumerate() {
+            println!("  {}) {option}", i + 1);
+        }
+    }
+}
+
+// the answer in the form the learner has to type it
+fn expected(question: &Question) -> String {
+    match &question.kind {
+        Kind::Choice(options, right) => format!("{}) {}", right + 1, options[*right]),
+        Kind::Output(line) => line.clone(),
+        Kind::ErrorLine(_, line) => format!("line {line}"),
+    }
+}
+
+fn is_right(question: &Question, given: &str) -> bool {
+    match &question.kind {
+        Kind::Choice(_, right) => given.trim().parse() == Ok(right + 1),
+        Kind::Output(line) => same_output(given, line),
+        Kind::ErrorLine(_, line) => given.trim().parse() == Ok(*line),
+    }
+}
+
+// quiz [lesson] [--seed n] [--check]
+pub fn command(args: &[String]) -> Result<(), String> {
+    let mut questions = questions()?;
+    if args.iter().any(|arg| arg == "--check") {
+        return check(&questions);
+    }
+
+    let mut args = args.to_vec();
+    let mut random = match crate::take_option(&mut args, "--seed") {
+        Some(seed) => Random::new(
+            seed.parse()
+                .map_err(|_| format!("--seed needs a number, not `{seed}`"))?,
+        ),
+        None => Random::from_time(),
+    };
+    if let Some(lesson) = args.first() {
+        lessons::find(lesson)
+            .ok_or_else(|| format!("unknown lesson `{lesson}`, see `localplayground list`"))?;
+        questions.retain(|question| question.lesson() == lesson);
+        if questions.is_empty() {
+            return Err(format!("there are no questions about {lesson} yet"));
+        }
+    }
+    random.shuffle(&mut questions);
+    for question in &mut questions {
+        shuffle_options(question, &mut random);
+    }
+
+    println!(
+        "{} questions, an empty answer or Ctrl-D ends the quiz",
+        questions.len()
+    );
+    let stdin = io::stdin();
+    let (mut asked, mut score) = (0, 0);
+    for (i, question) in questions.iter().enumerate() {
+        ask(question, i + 1, questions.len());
+        print!("> ");
+        io::stdout().flush().map_err(|e| e.to_string())?;
+        let mut answer = String::new();
+        stdin
+            .lock()
+            .read_line(&mut answer)
+            .map_err(|e| e.to_string())?;
+        if answer.trim().is_empty() {
+            break;
+        }
+
+        asked += 1;
+        if is_right(question, &answer) {
+            score += 1;
+            println!("right!");
+        } else {
+            println!("wrong, it's {}", expected(question));
+        }
+        for line in &question.explain {
+            println!("  {line}");
+        }
+        println!("  see {}", question.link());
+    }
+    println!("\nscore: {score}/{asked}");
+    Ok(())
+}
+
+// quiz --check: the answers in the bank have to match reality
+fn check(questions: &[Question]) -> Result<(), String> {
+    let mut wrong = 0;
+    for question in questions {
+        let problem = match &question.kind {
+            Kind::Choice(..) => None,
+            Kind::Output(line) => {
+                let printed = run_program(&question.code)?;
+                (!same_output(&printed, line)).then(|| format!("prints `{}`", printed.trim()))
+            }
+            Kind::ErrorLine(snippet, line) => {
+                let (_, diagnostics) = compile_fail::check(snippet)?;
+                match compile_fail::error_lines(&diagnostics).first() {
+                    Some(reported) if reported == line => None,
+                    Some(reported) => Some(format!("rustc reports line {reported}")),
+                    None => Some("rustc reports no error".to_string()),
+                }
+            }
+        };
+        let topic_known = question.link() != question.topic;
+        let place = format!("questions.txt:{}", question.line);
+        match problem {
+            None if topic_known => println!("ok    {place:<18} {}", question.topic),
+            None => {
+                wrong += 1;
+                println!("FAIL  {place:<18} no function {}", question.topic);
+            }
+            Some(problem) => {
+                wrong += 1;
+                println!(
+                    "FAIL  {place:<18} expected {}, {problem}",
+                    expected(question)
+                );
+            }
+        }
+    }
+    println!("{} questions checked, {wrong} wrong", questions.len());
+    match wrong {
+        0 => Ok(()),
+        n => Err(format!("{n} question(s) need fixing")),
+    }
+}
+
+fn run_program(code: &str) -> Result<String, String> {
+    let scratch = Scratch::new("quiz")?;
+    let file = scratch.write("main.rs", code)?;
+    let binary = scratch.path().join("question");
+    let compiled = toolchain::rustc()
+        .args(["-A", "warnings", "--color", "never", "-o"])
+        .arg(&binary)
+        .arg(&file)
+        .output()
+        .map_err(|e| format!("cannot run rustc: {e}"))?;
+    if !compiled.status.success() {
+        return Ok(String::from_utf8_lossy(&compiled.stderr).into_owned());
+    }
+    Ok(sandbox::run(&Command::new(&binary), &Limits::default())?.stdout)
+}
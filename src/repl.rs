@@ -0,0 +1,222 @@
+/*
+    Snippet REPL
+*/
+// `localplayground repl` reads Rust statements, wraps them into a `fn main` and
+// compiles them with the local rustc in a scratch directory. Statements that ran
+// fine stay as context for the next ones, so
+//     >> let add_one_v2 = |x: u32| -> u32 { x + 1 };
+//     >> add_one_v2(4)
+// prints 5. An entry without a trailing `;` is an expression and its value is printed
+// with {:?}. Items (fn, struct, impl, use ...) are put outside of main.
+
+use std::io::{self, BufRead, Write};
+use std::mem;
+use std::process::Command;
+
+use crate::compile_fail;
+use crate::explain;
+use crate::sandbox::{self, Limits, Outcome};
+use crate::toolchain::{self, Scratch};
+
+// printed right before the new statement, everything before it is output of the context
+const MARKER: &str = "\u{1}localplayground-repl\u{1}";
+
+const HELP: &str = "enter Rust statements, items or an expression without `;` to print its value
+    :context   show the statements and items kept so far
+    :reset     forget them
+    :quit      leave (or Ctrl-D)";
+
+enum Entry {
+    Item(String),
+    Statement(String),
+    Expression(String),
+}
+
+impl Entry {
+    fn parse(source: &str) -> Entry {
+        let source = source.trim().to_string();
+        let item_starts = [
+            "fn ", "pub ", "struct ", "enum ", "impl", "trait ", "use ", "mod ", "type ", "const ",
+            "static ", "#[",
+        ];
+        if item_starts.iter().any(|start| source.starts_with(start)) {
+            Entry::Item(source)
+        } else if source.ends_with(';') || source.ends_with('}') {
+            Entry::Statement(source)
+        } else {
+            Entry::Expression(source)
+        }
+    }
+}
+
+pub struct Session {
+    items: Vec<String>,
+    statements: Vec<String>,
+    scratch: Scratch,
+}
+
+impl Session {
+    pub fn new() -> Result<Session, String> {
+        Ok(Session {
+            items: Vec::new(),
+            statements: Vec::new(),
+            scratch: Scratch::new("repl")?,
+        })
+    }
+
+    fn program(&self, item: Option<&str>, statement: Option<&str>) -> String {
+        let mut program = String::new();
+        for item in self.items.iter().map(String::as_str).chain(item) {
+            program.push_str(item);
+            program.push('\n');
+        }
+        program.push_str("fn main() {\n");
+        for statement in &self.statements {
+            program.push_str(statement);
+            program.push('\n');
+        }
+        program.push_str(&format!("println!(\"{MARKER}\");\n"));
+        if let Some(statement) = statement {
+            program.push_str(statement);
+            program.push('\n');
+        }
+        program.push_str("}\n");
+        program
+    }
+
+    // compiles and runs the entry, returns what should be shown to the user
+    pub fn eval(&mut self, source: &str) -> Result<String, String> {
+        let entry = Entry::parse(source);
+        let program = match &entry {
+            Entry::Item(item) => self.program(Some(item), None),
+            Entry::Statement(statement) => self.program(None, Some(statement)),
+            Entry::Expression(expression) => self.program(
+                None,
+                Some(&format!("println!(\"{{:?}}\", {{ {expression} }});")),
+            ),
+        };
+
+        let file = self.scratch.write("main.rs", &program)?;
+        let binary = self.scratch.path().join("snippet");
+        let compiled = toolchain::rustc()
+            .args(["-A", "warnings", "--color", "never", "-o"])
+            .arg(&binary)
+            .arg(&file)
+            .output()
+            .map_err(|e| format!("cannot run rustc: {e}"))?;
+        if !compiled.status.success() {
+            let mut shown = String::from_utf8_lossy(&compiled.stderr).into_owned();
+            for code in compile_fail::error_codes(&shown) {
+                if let Some(hint) = explain::hint(&code) {
+                    shown.push_str(&format!("{hint}\n"));
+                }
+            }
+            return Ok(shown);
+        }
+
+        // an endless loop or a huge allocation in the entry must not take the REPL down
+        let mut snippet = Command::new(&binary);
+        snippet.env("RUST_BACKTRACE", "0");
+        let ran = sandbox::run(&snippet, &Limits::default())?;
+        let mut shown = match ran.stdout.split_once(MARKER) {
+            Some((_, new)) => new.trim_start_matches('\n').to_string(),
+            None => ran.stdout.clone(),
+        };
+        shown.push_str(&ran.stderr);
+        if let Outcome::Timeout | Outcome::OutOfMemory = ran.outcome {
+            shown.push_str(&format!("[the snippet {}]\n", ran.outcome));
+        }
+
+        // only what compiled and ran fine becomes context for the next entries
+        if ran.outcome == Outcome::Ok {
+            match entry {
+                Entry::Item(item) => self.items.push(item),
+                Entry::Statement(statement) => self.statements.push(statement),
+                Entry::Expression(_) => (),
+            }
+        }
+        Ok(shown)
+    }
+
+    fn context(&self) -> String {
+        self.program(None, None)
+            .replace(&format!("println!(\"{MARKER}\");\n"), "")
+    }
+
+    fn reset(&mut self) {
+        self.items.clear();
+        self.statements.clear();
+    }
+}
+
+// an entry is complete once all brackets are closed and the last line doesn't go on
+fn complete(entry: &str) -> bool {
+    let mut depth = 0i32;
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in entry.chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '(' | '[' | '{' if !in_string => depth += 1,
+            ')' | ']' | '}' if !in_string => depth -= 1,
+            _ => (),
+        }
+    }
+    let last = entry.trim_end();
+    let continues = [
+        "=", "+", "-", "*", "/", ",", ".", "|", "&&", "||", "->", "=>",
+    ]
+    .iter()
+    .any(|end| last.ends_with(end));
+    depth <= 0 && !in_string && !continues
+}
+
+pub fn command(_args: &[String]) -> Result<(), String> {
+    let mut session = Session::new()?;
+    println!("{HELP}");
+
+    let stdin = io::stdin();
+    let mut entry = String::new();
+    loop {
+        print!("{}", if entry.is_empty() { ">> " } else { ".. " });
+        io::stdout().flush().map_err(|e| e.to_string())?;
+
+        let mut line = String::new();
+        if stdin
+            .lock()
+            .read_line(&mut line)
+            .map_err(|e| e.to_string())?
+            == 0
+        {
+            println!();
+            return Ok(());
+        }
+        if entry.is_empty() {
+            match line.trim() {
+                "" => continue,
+                ":quit" | ":q" => return Ok(()),
+                ":reset" => {
+                    session.reset();
+                    continue;
+                }
+                ":context" => {
+                    print!("{}", session.context());
+                    continue;
+                }
+                ":help" => {
+                    println!("{HELP}");
+                    continue;
+                }
+                _ => (),
+            }
+        }
+
+        entry.push_str(&line);
+        // an empty line finishes an entry that would otherwise go on
+        if complete(&entry) || line.trim().is_empty() {
+            print!("{}", session.eval(&mem::take(&mut entry))?);
+        }
+    }
+}
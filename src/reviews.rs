@@ -0,0 +1,506 @@
+/*
+    Book reviews
+*/
+// collections::hash_map keeps its reviews in a HashMap that is gone when the function
+// returns. `localplayground reviews` keeps them in reviews.txt in the data directory,
+// one review per line with the fields separated by tabs:
+//     title <tab> author <tab> stars <tab> date <tab> text
+// tabs, line breaks and backslashes inside a field are written as \t, \n, \r and \\
+// (a bare \r at the end of the text would be read back as half of a \r\n and dropped).
+// Every change rewrites the whole file: into a temporary file next to it first, synced to
+// the disk, then renamed over reviews.txt. The rename is atomic, so after a crash there is
+// either the old file or the new one, never half of each. Every save has its own temporary
+// file, two playgrounds saving at once can't write into the same one. The later rename
+// still wins, so one of the two changes is lost, but the file is always whole.
+
+use std::env;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::progress;
+
+const USAGE: &str = "usage:
+    reviews [list] [--author <name>]
+    reviews show <title>
+    reviews add <title> <stars 1-5> <text> [--author <name>] [--date YYYY-MM-DD]
+    reviews rm <title> [--author <name>]
+the author is $USER unless --author says otherwise, adding a second review of the
+same book by the same author replaces the first";
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct Rating(u8);
+
+impl Rating {
+    pub fn new(stars: u8) -> Result<Rating, String> {
+        match stars {
+            1..=5 => Ok(Rating(stars)),
+            _ => Err(format!("a rating is 1 to 5 stars, not {stars}")),
+        }
+    }
+
+    pub fn stars(self) -> u8 {
+        self.0
+    }
+}
+
+#[derive(Clone)]
+pub struct Review {
+    pub title: String,
+    pub author: String,
+    pub rating: Rating,
+    // YYYY-MM-DD
+    pub date: String,
+    pub text: String,
+}
+
+fn escape(field: &str) -> String {
+    field
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+fn unescape(field: &str) -> String {
+    let mut text = String::new();
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => text.push('\t'),
+            Some('n') => text.push('\n'),
+            Some('r') => text.push('\r'),
+            Some(other) => text.push(other),
+            None => text.push('\\'),
+        }
+    }
+    text
+}
+
+fn days_in(year: u32, month: u32) -> u32 {
+    // every 4th year, but of the full centuries only every 4th
+    let leap = year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+    match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn check_date(date: &str) -> Result<(), String> {
+    let parts: Vec<&str> = date.split('-').collect();
+    let number = |part: &str, len: usize| {
+        (part.len() == len && part.bytes().all(|b| b.is_ascii_digit()))
+            .then(|| part.parse::<u32>().ok())
+            .flatten()
+    };
+    match parts[..] {
+        [year, month, day] => match (number(year, 4), number(month, 2), number(day, 2)) {
+            (Some(year), Some(month @ 1..=12), Some(day))
+                if (1..=days_in(year, month)).contains(&day) =>
+            {
+                Ok(())
+            }
+            _ => Err(format!("`{date}` is not a date like 2026-10-18")),
+        },
+        _ => Err(format!("`{date}` is not a date like 2026-10-18")),
+    }
+}
+
+impl Review {
+    fn parse(line: &str) -> Result<Review, String> {
+        let fields: Vec<String> = line.split('\t').map(unescape).collect();
+        let [title, author, stars, date, text] = &fields[..] else {
+            return Err(format!("expected 5 fields, found {}", fields.len()));
+        };
+        let stars = stars
+            .parse()
+            .map_err(|_| format!("`{stars}` is not a number of stars"))?;
+        check_date(date)?;
+        Ok(Review {
+            title: title.clone(),
+            author: author.clone(),
+            rating: Rating::new(stars)?,
+            date: date.clone(),
+            text: text.clone(),
+        })
+    }
+
+    fn line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\n",
+            escape(&self.title),
+            escape(&self.author),
+            self.rating.stars(),
+            self.date,
+            escape(&self.text)
+        )
+    }
+
+    // titles and authors are compared without case, "grimms' fairy tales" finds the book
+    fn is(&self, title: &str, author: &str) -> bool {
+        self.title.to_lowercase() == title.to_lowercase()
+            && self.author.to_lowercase() == author.to_lowercase()
+    }
+}
+
+pub struct ReviewStore {
+    path: PathBuf,
+    reviews: Vec<Review>,
+}
+
+impl ReviewStore {
+    // a missing file is an empty store
+    pub fn open(path: PathBuf) -> Result<ReviewStore, String> {
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(format!("cannot read {}: {e}", path.display())),
+        };
+        // unlike progress.txt a line we don't understand is an error, the next save would
+        // drop it and the review with it
+        let reviews = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(index, line)| {
+                Review::parse(line)
+                    .map_err(|e| format!("{} line {}: {e}", path.display(), index + 1))
+            })
+            .collect::<Result<Vec<Review>, String>>()?;
+        Ok(ReviewStore { path, reviews })
+    }
+
+    pub fn reviews(&self) -> &[Review] {
+        &self.reviews
+    }
+
+    pub fn find(&self, title: &str, author: &str) -> Option<&Review> {
+        self.reviews.iter().find(|review| review.is(title, author))
+    }
+
+    pub fn by_title(&self, title: &str) -> Vec<&Review> {
+        let title = title.to_lowercase();
+        self.reviews
+            .iter()
+            .filter(|review| review.title.to_lowercase() == title)
+            .collect()
+    }
+
+    pub fn by_author(&self, author: &str) -> Vec<&Review> {
+        let author = author.to_lowercase();
+        self.reviews
+            .iter()
+            .filter(|review| review.author.to_lowercase() == author)
+            .collect()
+    }
+
+    pub fn average(&self, title: &str) -> Option<f64> {
+        let reviews = self.by_title(title);
+        let stars: u32 = reviews.iter().map(|r| u32::from(r.rating.stars())).sum();
+        (!reviews.is_empty()).then(|| f64::from(stars) / reviews.len() as f64)
+    }
+
+    // every reviewed book once, sorted
+    pub fn titles(&self) -> Vec<&str> {
+        let mut titles: Vec<&str> = self.reviews.iter().map(|r| r.title.as_str()).collect();
+        titles.sort_by_key(|title| title.to_lowercase());
+        titles.dedup_by_key(|title| title.to_lowercase());
+        titles
+    }
+
+    pub fn add(&mut self, review: Review) -> Result<(), String> {
+        if self.find(&review.title, &review.author).is_some() {
+            return Err(format!(
+                "{} already reviewed {}",
+                review.author, review.title
+            ));
+        }
+        let mut reviews = self.reviews.clone();
+        reviews.push(review);
+        self.save(reviews)
+    }
+
+    pub fn update(&mut self, review: Review) -> Result<(), String> {
+        let mut reviews = self.reviews.clone();
+        let old = reviews
+            .iter_mut()
+            .find(|old| old.is(&review.title, &review.author))
+            .ok_or_else(|| format!("{} has no review of {}", review.author, review.title))?;
+        // the title keeps the spelling of the first review
+        *old = Review {
+            title: old.title.clone(),
+            ..review
+        };
+        self.save(reviews)
+    }
+
+    pub fn remove(&mut self, title: &str, author: &str) -> Result<Review, String> {
+        let mut reviews = self.reviews.clone();
+        let index = reviews
+            .iter()
+            .position(|review| review.is(title, author))
+            .ok_or_else(|| format!("{author} has no review of {title}"))?;
+        let removed = reviews.remove(index);
+        self.save(reviews)?;
+        Ok(removed)
+    }
+
+    // the store only changes once the file is written
+    fn save(&mut self, reviews: Vec<Review>) -> Result<(), String> {
+        let dir = self.path.parent().ok_or("reviews.txt needs a directory")?;
+        fs::create_dir_all(dir).map_err(|e| format!("cannot create {}: {e}", dir.display()))?;
+        // pid and a counter, like toolchain::Scratch
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let id = NEXT.fetch_add(1, Ordering::Relaxed);
+        let temporary = self
+            .path
+            .with_extension(format!("txt.{}-{id}.tmp", process::id()));
+        let contents: String = reviews.iter().map(Review::line).collect();
+        File::create(&temporary)
+            .and_then(|mut file| {
+                file.write_all(contents.as_bytes())?;
+                // on the disk, not only in the OS's cache, before it replaces the old file
+                file.sync_all()
+            })
+            .and_then(|()| fs::rename(&temporary, &self.path))
+            .map_err(|e| {
+                let _ = fs::remove_file(&temporary);
+                format!("cannot write {}: {e}", self.path.display())
+            })?;
+        // makes the rename itself durable, not every OS can open a directory for that
+        if let Ok(dir) = File::open(dir) {
+            let _ = dir.sync_all();
+        }
+        self.reviews = reviews;
+        Ok(())
+    }
+}
+
+fn path() -> Result<PathBuf, String> {
+    Ok(progress::data_dir()?.join("reviews.txt"))
+}
+
+fn author(option: Option<String>) -> Result<String, String> {
+    option
+        .or_else(|| env::var("USER").ok())
+        .filter(|author| !author.trim().is_empty())
+        .ok_or_else(|| "who wrote the review? add --author <name>".to_string())
+}
+
+fn list(store: &ReviewStore, author: Option<&str>) {
+    if let Some(author) = author {
+        for review in store.by_author(author) {
+            println!(
+                "{}/5  {}  {}",
+                review.rating.stars(),
+                review.date,
+                review.title
+            );
+        }
+        return;
+    }
+    if store.reviews().is_empty() {
+        println!(
+            "no reviews yet, add one with `localplayground reviews add <title> <stars> <text>`"
+        );
+        return;
+    }
+    println!("{:<40} {:<7} reviews", "title", "stars");
+    for title in store.titles() {
+        let average = store.average(title).unwrap_or(0.0);
+        println!("{title:<40} {average:<7.1} {}", store.by_title(title).len());
+    }
+}
+
+fn show(store: &ReviewStore, title: &str) -> Result<(), String> {
+    let reviews = store.by_title(title);
+    let Some(average) = store.average(title) else {
+        return Err(format!("nobody reviewed {title} yet"));
+    };
+    println!(
+        "{}: {average:.1} stars from {} review(s)",
+        reviews[0].title,
+        reviews.len()
+    );
+    for review in reviews {
+        println!(
+            "\n{}/5 by {} on {}",
+            review.rating.stars(),
+            review.author,
+            review.date
+        );
+        for line in review.text.lines() {
+            println!("    {line}");
+        }
+    }
+    Ok(())
+}
+
+// reviews [list|show|add|rm] ..., see USAGE
+pub fn command(args: &[String]) -> Result<(), String> {
+    let mut args = args.to_vec();
+    let author_option = crate::take_option(&mut args, "--author");
+    let date = crate::take_option(&mut args, "--date");
+    let mut store = ReviewStore::open(path()?)?;
+    let words: Vec<&str> = args.iter().map(String::as_str).collect();
+    match words[..] {
+        ["help"] => println!("{USAGE}"),
+        [] | ["list"] => list(&store, author_option.as_deref()),
+        ["show", title] => show(&store, title)?,
+        ["add", title, stars, ref text @ ..] if !text.is_empty() => {
+            let stars = stars
+                .parse()
+                .map_err(|_| format!("`{stars}` is not a number of stars"))?;
+            let date =
+                date.unwrap_or_else(|| progress::format_time(progress::now())[..10].to_string());
+            check_date(&date)?;
+            let review = Review {
+                title: title.to_string(),
+                author: author(author_option)?,
+                rating: Rating::new(stars)?,
+                date,
+                text: text.join(" "),
+            };
+            let message = format!("{} stars for {} by {}", stars, review.title, review.author);
+            if store.find(&review.title, &review.author).is_some() {
+                store.update(review)?;
+                println!("updated: {message}");
+            } else {
+                store.add(review)?;
+                println!("added: {message}");
+            }
+        }
+        ["rm", title] => {
+            let removed = store.remove(title, &author(author_option)?)?;
+            println!(
+                "removed the review of {} by {}",
+                removed.title, removed.author
+            );
+        }
+        _ => return Err(USAGE.to_string()),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{check_date, escape, unescape, Rating, Review, ReviewStore};
+    use crate::toolchain::Scratch;
+
+    fn review(title: &str, text: &str) -> Review {
+        Review {
+            title: title.to_string(),
+            author: String::from("ferris"),
+            rating: Rating(4),
+            date: String::from("2024-02-29"),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn escaped_fields_come_back_unchanged() {
+        let fields = [
+            "",
+            "plain",
+            "a\ttab",
+            "two\nlines",
+            "windows\r\nlines",
+            "ends with \r",
+            "C:\\books\\",
+            "\\t is no tab",
+        ];
+        for field in fields {
+            let escaped = escape(field);
+            assert!(!escaped.contains(['\t', '\n', '\r']), "{escaped:?}");
+            assert_eq!(unescape(&escaped), field);
+        }
+    }
+
+    #[test]
+    fn malformed_lines_are_errors() {
+        let lines = [
+            ("Dune\tferris\t4\t2024-01-01", "expected 5 fields, found 4"),
+            (
+                "Dune\tferris\tfour\t2024-01-01\tsand",
+                "`four` is not a number of stars",
+            ),
+            (
+                "Dune\tferris\t6\t2024-01-01\tsand",
+                "a rating is 1 to 5 stars, not 6",
+            ),
+            (
+                "Dune\tferris\t4\t2024-1-01\tsand",
+                "`2024-1-01` is not a date like 2026-10-18",
+            ),
+        ];
+        for (line, error) in lines {
+            assert_eq!(Review::parse(line).err().as_deref(), Some(error));
+        }
+    }
+
+    #[test]
+    fn dates_have_the_days_of_their_month() {
+        for date in ["2024-02-29", "2000-02-29", "2023-04-30", "2023-12-31"] {
+            assert!(check_date(date).is_ok(), "{date}");
+        }
+        let wrong = [
+            "2024-02-30",
+            "2024-02-31",
+            "2023-02-29",
+            "1900-02-29",
+            "2023-04-31",
+            "2023-13-01",
+            "2023-00-10",
+            "2023-01-00",
+        ];
+        for date in wrong {
+            assert!(check_date(date).is_err(), "{date}");
+        }
+    }
+
+    #[test]
+    fn saved_reviews_come_back_after_a_reload() {
+        let scratch = Scratch::new("reviews-test").unwrap();
+        let path = scratch.path().join("data").join("reviews.txt");
+        // no file yet, and no directory either
+        let mut store = ReviewStore::open(path.clone()).unwrap();
+        assert!(store.reviews().is_empty());
+        let text = "sand\tand\r\nworms \\o/\r";
+        store.add(review("Dune", text)).unwrap();
+        store.add(review("Emma", "")).unwrap();
+        store.remove("emma", "FERRIS").unwrap();
+
+        let reloaded = ReviewStore::open(path.clone()).unwrap();
+        assert_eq!(reloaded.titles(), ["Dune"]);
+        let dune = &reloaded.reviews()[0];
+        assert_eq!((dune.rating.stars(), dune.text.as_str()), (4, text));
+        // every temporary file was renamed over reviews.txt
+        let files: Vec<_> = fs::read_dir(path.parent().unwrap())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(files, ["reviews.txt"]);
+    }
+
+    #[test]
+    fn a_broken_line_keeps_the_store_closed() {
+        let scratch = Scratch::new("reviews-test").unwrap();
+        let path = scratch.path().join("reviews.txt");
+        fs::write(&path, review("Dune", "sand").line() + "Emma\tferris\n").unwrap();
+        let error = ReviewStore::open(path).err().unwrap();
+        assert!(
+            error.ends_with("line 2: expected 5 fields, found 2"),
+            "{error}"
+        );
+    }
+}
@@ -0,0 +1,213 @@
+/*
+    Sandboxed runs
+*/
+// Code we didn't write ourselves (REPL entries, exercise solutions, quiz programs) and
+// the demos that verify runs may loop forever, eat all memory or print without end.
+// They run as a child process: a wall clock timeout kills it, `ulimit` limits its
+// address space and cpu time, and of long output only the beginning and the end are kept.
+// The caller gets what happened as an Outcome instead of a hanging playground.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::Read;
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+pub struct Limits {
+    pub timeout: Duration,
+    pub memory_mb: u64,
+    pub cpu_seconds: u64,
+    // per stream, stdout and stderr
+    pub output_bytes: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Limits {
+        Limits {
+            timeout: Duration::from_secs(10),
+            memory_mb: 512,
+            cpu_seconds: 10,
+            output_bytes: 64 * 1024,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Ok,
+    // with the message of the panic
+    Panicked(String),
+    Timeout,
+    OutOfMemory,
+    // killed by a signal counts as 128 + signal, like the shell does it
+    ExitCode(i32),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Ok => write!(f, "finished"),
+            Outcome::Panicked(message) => write!(f, "panicked: {message}"),
+            Outcome::Timeout => write!(f, "took too long and had to be stopped"),
+            Outcome::OutOfMemory => write!(f, "ran out of memory"),
+            Outcome::ExitCode(code) => write!(f, "exited with code {code}"),
+        }
+    }
+}
+
+pub struct Run {
+    pub outcome: Outcome,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+// the command with the rlimits applied, `exec` keeps the pid so the timeout kills the
+// program itself and not only the shell
+fn limited(command: &Command, limits: &Limits) -> Command {
+    let mut limited = if cfg!(unix) {
+        let mut shell = Command::new("sh");
+        shell
+            .arg("-c")
+            .arg(format!(
+                "ulimit -v {} && ulimit -t {} && exec \"$0\" \"$@\"",
+                limits.memory_mb * 1024,
+                limits.cpu_seconds
+            ))
+            .arg(command.get_program());
+        shell
+    } else {
+        Command::new(command.get_program())
+    };
+    limited.args(command.get_args());
+    for (key, value) in command.get_envs() {
+        match value {
+            Some(value) => limited.env(key, value),
+            None => limited.env_remove(key),
+        };
+    }
+    if let Some(dir) = command.get_current_dir() {
+        limited.current_dir(dir);
+    }
+    limited
+}
+
+// keeps reading after the limit, otherwise the child blocks on a full pipe.
+// Half of the limit is the beginning, half the end: panics and test summaries come last,
+// what was thrown away in between is marked with [...].
+fn capture(mut pipe: impl Read + Send + 'static, limit: usize) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut head = Vec::new();
+        let mut tail = VecDeque::new();
+        let mut truncated = false;
+        let mut buffer = [0; 8192];
+        while let Ok(read @ 1..) = pipe.read(&mut buffer) {
+            for &byte in &buffer[..read] {
+                if head.len() < limit / 2 {
+                    head.push(byte);
+                    continue;
+                }
+                if tail.len() == limit / 2 {
+                    tail.pop_front();
+                    truncated = true;
+                }
+                tail.push_back(byte);
+            }
+        }
+        if truncated {
+            head.extend_from_slice(b"\n[...]\n");
+        }
+        head.extend(tail);
+        head
+    })
+}
+
+pub fn run(command: &Command, limits: &Limits) -> Result<Run, String> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let mut child = limited(command, limits)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("cannot run {program}: {e}"))?;
+    let stdout = capture(child.stdout.take().expect("piped"), limits.output_bytes);
+    let stderr = capture(child.stderr.take().expect("piped"), limits.output_bytes);
+
+    let started = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Some(status),
+            Ok(None) if started.elapsed() >= limits.timeout => {
+                let _ = child.kill();
+                let _ = child.wait();
+                break None;
+            }
+            Ok(None) => thread::sleep(Duration::from_millis(10)),
+            Err(e) => return Err(format!("cannot wait for {program}: {e}")),
+        }
+    };
+
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    let stderr = String::from_utf8_lossy(&stderr).into_owned();
+    Ok(Run {
+        outcome: outcome(status, &stderr),
+        stdout: String::from_utf8_lossy(&stdout).into_owned(),
+        stderr,
+    })
+}
+
+fn outcome(status: Option<ExitStatus>, stderr: &str) -> Outcome {
+    // no status means we killed it
+    let Some(status) = status else {
+        return Outcome::Timeout;
+    };
+    if status.success() {
+        return Outcome::Ok;
+    }
+    // what the allocator says before it aborts, when ulimit -v says no
+    if stderr.contains("memory allocation of") {
+        return Outcome::OutOfMemory;
+    }
+    if let Some(message) = panic_message(stderr) {
+        return Outcome::Panicked(message);
+    }
+    match (status.code(), signal(status)) {
+        (Some(code), _) => Outcome::ExitCode(code),
+        // SIGKILL or SIGXCPU from the cpu limit
+        (None, Some(9 | 24)) => Outcome::Timeout,
+        (None, Some(signal)) => Outcome::ExitCode(128 + signal),
+        (None, None) => Outcome::ExitCode(-1),
+    }
+}
+
+#[cfg(unix)]
+fn signal(status: ExitStatus) -> Option<i32> {
+    std::os::unix::process::ExitStatusExt::signal(&status)
+}
+
+#[cfg(not(unix))]
+fn signal(_status: ExitStatus) -> Option<i32> {
+    None
+}
+
+// thread 'main' panicked at src/main.rs:4:13:
+// index out of bounds: the len is 7 but the index is 16
+// (older compilers: panicked at 'index out of bounds ...', src/main.rs:4:13)
+fn panic_message(stderr: &str) -> Option<String> {
+    let mut lines = stderr.lines();
+    while let Some(line) = lines.next() {
+        let Some((_, rest)) = line.split_once("panicked at ") else {
+            continue;
+        };
+        if rest.ends_with(':') {
+            return Some(lines.next().unwrap_or_default().to_string());
+        }
+        let old_style = rest
+            .strip_prefix('\'')
+            .and_then(|rest| rest.rsplit_once("', "))
+            .map(|(message, _)| message);
+        return Some(old_style.unwrap_or(rest).to_string());
+    }
+    None
+}
@@ -0,0 +1,254 @@
+/*
+    Searching the lessons
+*/
+// `localplayground search <query>` answers "where did we explain entry" or
+// "which lesson uses downcast_ref". Every lesson line is indexed with
+//     the words of its comments (prose),
+//     the identifiers of its code,
+//     the paths it spells out, like HashMap::new,
+//     and the std paths it probably uses: `.borrow_mut()` in a function that mentions
+//     RefCell is indexed as RefCell::borrow_mut. Only the types and APIs of
+//     coverage/apis.txt are guessed, and each call at most once.
+// Spelled out paths rank highest, prose lowest. Each hit is shown with the lesson,
+// the function and the lines around it.
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use crate::coverage;
+use crate::lessons::{Lesson, LESSONS};
+use crate::source;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Path,
+    GuessedPath,
+    Identifier,
+    Prose,
+}
+
+impl Kind {
+    fn score(self) -> usize {
+        match self {
+            Kind::Path => 10,
+            Kind::GuessedPath => 7,
+            Kind::Identifier => 6,
+            Kind::Prose => 3,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Kind::Path | Kind::GuessedPath => "std path",
+            Kind::Identifier => "code",
+            Kind::Prose => "prose",
+        }
+    }
+}
+
+// a lesson line, by index into LESSONS and line number
+type Place = (usize, usize);
+
+struct Index {
+    // lower case term -> where it occurs and as what
+    terms: HashMap<String, Vec<(Place, Kind)>>,
+}
+
+impl Index {
+    fn add(&mut self, term: &str, place: Place, kind: Kind) {
+        let postings = self.terms.entry(term.to_lowercase()).or_default();
+        if !postings.contains(&(place, kind)) {
+            postings.push((place, kind));
+        }
+    }
+}
+
+fn words(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|word| word.chars().count() > 1)
+}
+
+// the lines that only register the lesson don't say anything about Rust
+fn registration(lines: &[&str]) -> Vec<bool> {
+    let mut inside = false;
+    lines
+        .iter()
+        .map(|line| {
+            if line.starts_with("pub const LESSON") {
+                inside = true;
+            }
+            let skipped = inside;
+            if *line == "};" {
+                inside = false;
+            }
+            skipped
+        })
+        .collect()
+}
+
+fn build() -> Result<Index, String> {
+    let modules = coverage::modules()?;
+    let mut index = Index {
+        terms: HashMap::new(),
+    };
+    for (lesson_index, lesson) in LESSONS.iter().enumerate() {
+        let lines: Vec<&str> = lesson.source.lines().collect();
+        let skipped = registration(&lines);
+        let mut in_block_comment = false;
+        for (i, line) in lines.iter().enumerate() {
+            let place = (lesson_index, i + 1);
+            if skipped[i] {
+                continue;
+            }
+            if in_block_comment || line.trim_start().starts_with("/*") {
+                in_block_comment = !line.contains("*/");
+                words(line).for_each(|word| index.add(word, place, Kind::Prose));
+                continue;
+            }
+            let code = source::code(line);
+            words(&line[code.len()..]).for_each(|word| index.add(word, place, Kind::Prose));
+            words(code).for_each(|word| index.add(word, place, Kind::Identifier));
+            let (paths, _) = source::paths_and_calls(code);
+            for path in paths {
+                index.add(&path, place, Kind::Path);
+            }
+        }
+
+        // a method called in a function that mentions a std type is probably that type's
+        // method, if the type has one of that name. Every other capitalized word would be
+        // a guess too, and a function naming many types would match every query
+        for function in source::functions(lesson.source) {
+            let names: Vec<&str> = function
+                .lines
+                .iter()
+                .flat_map(|(_, line)| words(source::code(line)))
+                .collect();
+            let code: Vec<&str> = function
+                .lines
+                .iter()
+                .map(|(_, line)| source::code(line))
+                .collect();
+            for (index_in_function, &(number, _)) in function.lines.iter().enumerate() {
+                let statement = coverage::statement(&code, index_in_function);
+                let (_, calls) = source::paths_and_calls(code[index_in_function]);
+                for call in calls {
+                    if let Some(module) = modules.iter().find(|module| {
+                        module.apis.iter().any(|api| api == call)
+                            && module.mentioned(&names, statement)
+                    }) {
+                        index.add(
+                            &format!("{}::{call}", module.name),
+                            (lesson_index, number),
+                            Kind::GuessedPath,
+                        );
+                    }
+                }
+            }
+        }
+    }
+    Ok(index)
+}
+
+struct Hit {
+    place: Place,
+    score: usize,
+    kinds: Vec<Kind>,
+}
+
+fn search(index: &Index, query: &str) -> Vec<Hit> {
+    let mut hits: Vec<Hit> = Vec::new();
+    let terms = query
+        .split_whitespace()
+        .map(|term| term.trim_matches('`').trim_end_matches("()").to_lowercase())
+        .filter(|term| !term.is_empty());
+    for term in terms {
+        for (key, postings) in &index.terms {
+            // exact matches count fully, longer names that contain the term half
+            let weight = if *key == term {
+                2
+            } else if term.chars().count() >= 3 && key.contains(&term) {
+                1
+            } else {
+                continue;
+            };
+            for &(place, kind) in postings {
+                let score = kind.score() * weight;
+                match hits.iter_mut().find(|hit| hit.place == place) {
+                    Some(hit) => {
+                        hit.score += score;
+                        if !hit.kinds.contains(&kind) {
+                            hit.kinds.push(kind);
+                        }
+                    }
+                    None => hits.push(Hit {
+                        place,
+                        score,
+                        kinds: vec![kind],
+                    }),
+                }
+            }
+        }
+    }
+    hits.sort_by(|a, b| b.score.cmp(&a.score).then(a.place.cmp(&b.place)));
+    hits
+}
+
+// the function a line belongs to, if it is in one
+fn function_at(lesson: &Lesson, line: usize) -> Option<&'static str> {
+    source::functions(lesson.source)
+        .into_iter()
+        .find(|function| function.lines.iter().any(|&(number, _)| number == line))
+        .map(|function| function.name)
+}
+
+// search <query> [--all]
+pub fn command(args: &[String]) -> Result<(), String> {
+    let all = args.iter().any(|arg| arg == "--all");
+    let query: Vec<&str> = args
+        .iter()
+        .filter(|arg| *arg != "--all")
+        .map(String::as_str)
+        .collect();
+    if query.is_empty() {
+        return Err("usage: search <query> [--all]".to_string());
+    }
+
+    let hits = search(&build()?, &query.join(" "));
+    if hits.is_empty() {
+        return Err(format!("nothing found for `{}`", query.join(" ")));
+    }
+    let shown = if all { hits.len() } else { hits.len().min(10) };
+    // `search ... | head` closes the pipe early, that's not an error
+    match print(&hits, shown, &mut io::stdout().lock()) {
+        Err(e) if e.kind() != io::ErrorKind::BrokenPipe => Err(format!("cannot print: {e}")),
+        _ => Ok(()),
+    }
+}
+
+fn print(hits: &[Hit], shown: usize, out: &mut impl Write) -> io::Result<()> {
+    for hit in &hits[..shown] {
+        let (lesson_index, line) = hit.place;
+        let lesson = &LESSONS[lesson_index];
+        let place = match function_at(lesson, line) {
+            Some(function) => format!("{}::{function}", lesson.name),
+            None => lesson.name.to_string(),
+        };
+        let kinds: Vec<&str> = hit.kinds.iter().map(|kind| kind.label()).collect();
+        writeln!(
+            out,
+            "{place}  src/lessons/{}.rs:{line}  ({})",
+            lesson.name,
+            kinds.join(", ")
+        )?;
+        let lines: Vec<&str> = lesson.source.lines().collect();
+        for number in line.saturating_sub(1).max(1)..=(line + 1).min(lines.len()) {
+            let marker = if number == line { '>' } else { ' ' };
+            writeln!(out, "  {marker}{number:>4} | {}", lines[number - 1])?;
+        }
+        writeln!(out)?;
+    }
+    if shown < hits.len() {
+        writeln!(out, "{} more, --all shows them", hits.len() - shown)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,240 @@
+/*
+    Reading lesson sources
+*/
+// The tools that look at the lessons themselves (like the output check) don't need
+// a real parser, the lesson files are plain enough to be read line by line.
+
+pub struct Function<'a> {
+    pub name: &'a str,
+    // line numbers start at 1, like in the editor
+    pub lines: Vec<(usize, &'a str)>,
+}
+
+// all functions with their lines, nested functions stay part of the outer one
+pub fn functions(source: &str) -> Vec<Function<'_>> {
+    let mut functions = Vec::new();
+    let mut current: Option<Function> = None;
+    let mut depth = 0;
+    let mut in_block_comment = false;
+
+    for (index, line) in source.lines().enumerate() {
+        let number = index + 1;
+        if in_block_comment || line.trim_start().starts_with("/*") {
+            in_block_comment = !line.contains("*/");
+            continue;
+        }
+        if current.is_none() {
+            match fn_name(line) {
+                Some(name) => {
+                    current = Some(Function {
+                        name,
+                        lines: Vec::new(),
+                    })
+                }
+                None => continue,
+            }
+        }
+        let Some(function) = current.as_mut() else {
+            continue;
+        };
+        function.lines.push((number, line));
+        let (opened, closed) = braces(line);
+        depth = (depth + opened).saturating_sub(closed);
+        // the body starts with the first brace, a where clause may come before it
+        if depth == 0 && opened + closed > 0 {
+            functions.extend(current.take());
+        } else if depth == 0 && code(line).trim_end().ends_with(';') {
+            // a trait method without a default body
+            current = None;
+        }
+    }
+    functions
+}
+
+// the lines of `mod name { ... }`, from the mod line to its closing brace
+pub fn module<'a>(source: &'a str, name: &str) -> Option<Vec<&'a str>> {
+    let start = format!("mod {name} {{");
+    let mut lines = source
+        .lines()
+        .skip_while(|line| line.trim_start().trim_start_matches("pub ") != start);
+    let mut module = Vec::new();
+    let mut depth = 0;
+    for line in lines.by_ref() {
+        module.push(line);
+        let (opened, closed) = braces(line);
+        depth = (depth + opened).saturating_sub(closed);
+        if depth == 0 {
+            return Some(module);
+        }
+    }
+    None
+}
+
+// the type of the impl block around a line (1-based), `impl fmt::Display for Receipt {`
+// is Receipt. The block starts at the first line above that is indented less.
+pub fn impl_type(source: &str, line: usize) -> Option<&str> {
+    let lines: Vec<&str> = source.lines().collect();
+    let indent = |line: &str| line.len() - line.trim_start().len();
+    let inner = indent(lines.get(line.checked_sub(1)?)?);
+    let outer = lines[..line - 1]
+        .iter()
+        .rev()
+        .filter(|line| !line.trim().is_empty())
+        .find(|line| indent(line) < inner)?;
+    let mut rest = outer.trim_start().strip_prefix("impl")?;
+    // impl<T> Stack<T>
+    if rest.starts_with('<') {
+        let mut depth = 0;
+        for (i, c) in rest.char_indices() {
+            match c {
+                '<' => depth += 1,
+                '>' => depth -= 1,
+                _ => continue,
+            }
+            if depth == 0 {
+                rest = &rest[i + 1..];
+                break;
+            }
+        }
+    }
+    if let Some((_, name)) = rest.rsplit_once(" for ") {
+        rest = name;
+    }
+    identifiers(code(rest))
+        .into_iter()
+        .map(|(_, name)| name)
+        .find(|name| name.starts_with(char::is_uppercase))
+}
+
+// the lines of `struct name`, up to the end of its fields
+pub fn struct_lines<'a>(source: &'a str, name: &str) -> Vec<&'a str> {
+    let is_start = |line: &str| {
+        let line = line.trim_start();
+        let line = line
+            .strip_prefix("pub(crate) ")
+            .or_else(|| line.strip_prefix("pub "))
+            .unwrap_or(line);
+        line.strip_prefix("struct ")
+            .and_then(|rest| rest.strip_prefix(name))
+            .is_some_and(|rest| !rest.starts_with(|c: char| c.is_alphanumeric() || c == '_'))
+    };
+    let mut lines = Vec::new();
+    let mut depth = 0;
+    for line in source.lines().skip_while(|line| !is_start(line)) {
+        lines.push(line);
+        let (opened, closed) = braces(line);
+        depth = (depth + opened).saturating_sub(closed);
+        // `struct Meters(f64);` and `struct Draft;` end on their first line
+        if depth == 0 && (closed > 0 || code(line).trim_end().ends_with(';')) {
+            break;
+        }
+    }
+    lines
+}
+
+// `fn name(` or `pub fn name<T>(` at the start of a line
+fn fn_name(line: &str) -> Option<&str> {
+    let line = line.trim_start();
+    let rest = ["fn ", "pub fn ", "pub(crate) fn "]
+        .iter()
+        .find_map(|prefix| line.strip_prefix(prefix))?;
+    let end = rest.find(|c: char| !(c.is_alphanumeric() || c == '_'))?;
+    Some(&rest[..end])
+}
+
+// where the `//` comment of a line starts, comments inside string literals don't count
+pub fn comment_start(line: &str) -> Option<usize> {
+    let mut in_string = false;
+    let mut escaped = false;
+    let bytes = line.as_bytes();
+    for (i, &byte) in bytes.iter().enumerate() {
+        match byte {
+            _ if escaped => escaped = false,
+            b'\\' if in_string => escaped = true,
+            b'"' => in_string = !in_string,
+            b'/' if !in_string && bytes.get(i + 1) == Some(&b'/') => return Some(i),
+            _ => (),
+        }
+    }
+    None
+}
+
+// the line without its trailing comment
+pub fn code(line: &str) -> &str {
+    match comment_start(line) {
+        Some(start) => &line[..start],
+        None => line,
+    }
+}
+
+// identifiers with their byte offset
+pub fn identifiers(code: &str) -> Vec<(usize, &str)> {
+    let mut found = Vec::new();
+    let mut start = None;
+    for (i, c) in code.char_indices().chain([(code.len(), ' ')]) {
+        match (start, c.is_alphanumeric() || c == '_') {
+            (None, true) => start = Some(i),
+            (Some(from), false) => {
+                found.push((from, &code[from..i]));
+                start = None;
+            }
+            _ => (),
+        }
+    }
+    found
+}
+
+// `a::b` and `.method(`, `.method::<T>(` in a line of code
+pub fn paths_and_calls(code: &str) -> (Vec<String>, Vec<&str>) {
+    let mut paths = Vec::new();
+    let mut calls = Vec::new();
+    let identifiers = identifiers(code);
+    for (i, &(at, name)) in identifiers.iter().enumerate() {
+        let end = at + name.len();
+        let rest = &code[end..];
+        if code[..at].ends_with('.') && (rest.starts_with('(') || rest.starts_with("::<")) {
+            calls.push(name);
+        }
+        // `HashMap::<K, V>::new` is HashMap::new
+        let mut after = end;
+        if rest.starts_with("::<") {
+            let mut depth = 0;
+            for (offset, c) in rest.char_indices() {
+                match c {
+                    '<' => depth += 1,
+                    '>' => depth -= 1,
+                    _ => continue,
+                }
+                if depth == 0 {
+                    after = end + offset + 1;
+                    break;
+                }
+            }
+        }
+        let next = identifiers[i + 1..].iter().find(|&&(at, _)| at >= after);
+        if let Some(&(next_at, next)) = next {
+            if &code[after..next_at] == "::" {
+                paths.push(format!("{name}::{next}"));
+            }
+        }
+    }
+    (paths, calls)
+}
+
+// counts opening and closing braces outside of strings and comments
+fn braces(line: &str) -> (usize, usize) {
+    let mut in_string = false;
+    let mut escaped = false;
+    let (mut opened, mut closed) = (0, 0);
+    for c in code(line).chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '{' if !in_string => opened += 1,
+            '}' if !in_string => closed += 1,
+            _ => (),
+        }
+    }
+    (opened, closed)
+}
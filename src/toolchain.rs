@@ -0,0 +1,55 @@
+/*
+    Local toolchain
+*/
+// Everything that compiles code with the locally installed rustc goes through here.
+// Nothing is downloaded, we only use what is already on the machine.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// RUSTC may point to another toolchain, like cargo does it
+pub fn rustc() -> Command {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let mut command = Command::new(rustc);
+    command.arg("--edition").arg("2021");
+    command
+}
+
+// a temporary directory which is removed again once it is dropped
+pub struct Scratch {
+    path: PathBuf,
+}
+
+impl Scratch {
+    pub fn new(purpose: &str) -> Result<Scratch, String> {
+        // pid and a counter keep parallel playgrounds and repeated calls apart
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let id = NEXT.fetch_add(1, Ordering::Relaxed);
+        let path =
+            env::temp_dir().join(format!("localplayground-{purpose}-{}-{id}", process::id()));
+        fs::create_dir_all(&path).map_err(|e| format!("cannot create {}: {e}", path.display()))?;
+        Ok(Scratch { path })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn write(&self, file: &str, contents: &str) -> Result<PathBuf, String> {
+        let path = self.path.join(file);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("cannot create {}: {e}", dir.display()))?;
+        }
+        fs::write(&path, contents).map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+        Ok(path)
+    }
+}
+
+impl Drop for Scratch {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
@@ -0,0 +1,164 @@
+/*
+    Expected output check
+*/
+// The lessons note what a print shows in a trailing comment:
+//     println!("{}", b); // 46, because b wasn't shadowed
+// The expected output is the comment up to the first comma, or the text between
+// the quotes if the comment starts with one ( // "jer" ). Only lines that start with
+// println! count, so explanations behind match arms are left alone.
+// `localplayground verify` runs every demo in a child process, captures its stdout
+// and checks that the annotated lines show up in that order.
+
+use std::env;
+use std::process::Command;
+
+use crate::lessons::{self, Demo, Lesson, LESSONS};
+use crate::sandbox::{self, Limits, Outcome};
+use crate::source::{self, Function};
+
+pub struct Expectation {
+    pub line: usize,
+    pub text: String,
+}
+
+pub struct Mismatch {
+    pub lesson: &'static str,
+    pub demo: &'static str,
+    pub expectation: Expectation,
+    // what the demo printed at the point where the expectation was looked for
+    pub actual: Option<String>,
+}
+
+// the expected text of a println! line, if it has an annotation
+pub fn annotation(line: &str) -> Option<String> {
+    if !line.trim_start().starts_with("println!(") {
+        return None;
+    }
+    let comment = line[source::comment_start(line)? + 2..].trim();
+    let text = match comment.strip_prefix('"') {
+        Some(quoted) => &quoted[..quoted.find('"')?],
+        None => comment.split(',').next()?.trim(),
+    };
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+// the annotations of a demo in the order they are printed, the annotations of
+// helper functions it calls are included where they are called
+pub fn expectations(lesson: &Lesson, demo: &Demo) -> Vec<Expectation> {
+    let functions = source::functions(lesson.source);
+    // demos are free functions, a method of the same name (Shop::checkout) is not the demo
+    let Some(function) = functions
+        .iter()
+        .find(|f| f.name == demo.name && f.lines[0].1.starts_with("fn "))
+    else {
+        return Vec::new();
+    };
+    let helpers: Vec<&Function> = functions
+        .iter()
+        .filter(|f| lesson.demo(f.name).is_none())
+        .collect();
+
+    let mut expectations = Vec::new();
+    for &(number, line) in &function.lines {
+        let code = source::code(line);
+        for helper in &helpers {
+            if code.contains(&format!("{}(", helper.name)) && !code.contains("fn ") {
+                expectations.extend(annotations_of(helper));
+            }
+        }
+        if let Some(text) = annotation(line) {
+            expectations.push(Expectation { line: number, text });
+        }
+    }
+    expectations
+}
+
+fn annotations_of(function: &Function) -> Vec<Expectation> {
+    function
+        .lines
+        .iter()
+        .filter_map(|&(line, text)| {
+            Some(Expectation {
+                line,
+                text: annotation(text)?,
+            })
+        })
+        .collect()
+}
+
+// runs the demo through our own binary, so panics and exits can't take the check down
+fn captured_output(lesson: &Lesson, demo: &Demo) -> Result<String, String> {
+    let exe = env::current_exe().map_err(|e| format!("cannot find the playground binary: {e}"))?;
+    let mut command = Command::new(exe);
+    command.args(["run", lesson.name, demo.name]);
+    let ran = sandbox::run(&command, &Limits::default())?;
+    // panics are caught by `run` itself, so this is a demo that hangs or allocates without end
+    if ran.outcome != Outcome::Ok {
+        eprintln!("warning: {}::{} {}", lesson.name, demo.name, ran.outcome);
+    }
+    Ok(ran.stdout)
+}
+
+pub fn check_demo(lesson: &'static Lesson, demo: &'static Demo) -> Result<Vec<Mismatch>, String> {
+    if expectations(lesson, demo).is_empty() {
+        return Ok(Vec::new());
+    }
+    let output = captured_output(lesson, demo)?;
+    Ok(compare(lesson, demo, &output))
+}
+
+// `output` is what `run <lesson> <demo>` printed, starting with the demo's header line
+pub fn compare(lesson: &'static Lesson, demo: &'static Demo, output: &str) -> Vec<Mismatch> {
+    let printed: Vec<&str> = output.lines().skip(1).map(str::trim_end).collect();
+
+    let mut mismatches = Vec::new();
+    let mut position = 0;
+    for expectation in expectations(lesson, demo) {
+        match printed[position..]
+            .iter()
+            .position(|line| *line == expectation.text)
+        {
+            Some(offset) => position += offset + 1,
+            None => mismatches.push(Mismatch {
+                lesson: lesson.name,
+                demo: demo.name,
+                actual: printed.get(position).map(|line| line.to_string()),
+                expectation,
+            }),
+        }
+    }
+    mismatches
+}
+
+// verify [lesson]
+pub fn command(args: &[String]) -> Result<(), String> {
+    let selected: Vec<&'static Lesson> = match args.first() {
+        Some(name) => vec![lessons::find(name).ok_or_else(|| format!("unknown lesson `{name}`"))?],
+        None => LESSONS.iter().collect(),
+    };
+
+    let (mut checked, mut wrong) = (0, 0);
+    for lesson in selected {
+        for demo in lesson.demos {
+            checked += expectations(lesson, demo).len();
+            for mismatch in check_demo(lesson, demo)? {
+                wrong += 1;
+                println!(
+                    "src/lessons/{}.rs:{} ({}): comment says `{}`, but the demo printed `{}`",
+                    mismatch.lesson,
+                    mismatch.expectation.line,
+                    mismatch.demo,
+                    mismatch.expectation.text,
+                    mismatch.actual.as_deref().unwrap_or("nothing more"),
+                );
+            }
+        }
+    }
+    println!("{checked} annotated lines checked, {wrong} wrong");
+    match wrong {
+        0 => Ok(()),
+        n => Err(format!(
+            "{n} output comment(s) don't match what the code prints"
+        )),
+    }
+}
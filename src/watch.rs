@@ -0,0 +1,200 @@
+/*
+    Watch mode
+*/
+// `localplayground watch <lesson>` re-runs the lesson every time its source is saved,
+// `localplayground watch <exercise>` checks the solution every time it is saved.
+// There's no file system notification in std, so we poll the modification times.
+// Editors often write a file several times for one save, we wait until the files
+// have been quiet for a moment before running anything.
+// Lessons are compiled into the playground, so for them the playground rebuilds itself
+// with cargo and the fresh build runs the lesson. That only works in the source tree
+// the playground was built from. A lesson is more than its .rs file: the data it pulls in
+// with include_str!, the C code build.rs compiles for it and its exercises are watched
+// too, and the exercises with a solution are checked after the lesson ran.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::exercises;
+use crate::lessons;
+
+const POLL: Duration = Duration::from_millis(250);
+// how long the files have to stay unchanged before we run
+const QUIET: Duration = Duration::from_millis(400);
+
+enum Target {
+    Lesson(&'static str),
+    Exercise(&'static str, PathBuf),
+}
+
+impl Target {
+    fn files(&self) -> Vec<PathBuf> {
+        match self {
+            Target::Lesson(name) => lesson_files(name),
+            Target::Exercise(_, solution) => vec![solution.clone()],
+        }
+    }
+
+    // the outcome is only printed, a failing run is what watching is for
+    fn run(&self) {
+        let result = match self {
+            Target::Lesson(name) => rebuild_and_run(name),
+            Target::Exercise(name, solution) => {
+                exercises::command(&[name.to_string(), solution.display().to_string()])
+            }
+        };
+        if let Err(message) = result {
+            println!("error: {message}");
+        }
+    }
+}
+
+fn source_tree() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+// the paths in `include_str!("data/prices.csv")` and the like, relative to the file
+fn included(source: &str) -> Vec<&str> {
+    ["include_str!(\"", "include_bytes!(\""]
+        .iter()
+        .flat_map(|start| source.split(start).skip(1))
+        .filter_map(|rest| rest.split_once('"').map(|(path, _)| path))
+        .collect()
+}
+
+fn lesson_exercises(lesson: &str) -> impl Iterator<Item = &'static exercises::Exercise> + '_ {
+    exercises::EXERCISES
+        .iter()
+        .filter(move |exercise| exercise.lesson == lesson)
+}
+
+fn lesson_files(name: &str) -> Vec<PathBuf> {
+    let lessons = source_tree().join("src/lessons");
+    let mut files = vec![lessons.join(format!("{name}.rs"))];
+    let source = lessons::find(name).map_or("", |lesson| lesson.source);
+    files.extend(
+        included(source)
+            .into_iter()
+            .filter(|path| *path != format!("{name}.rs"))
+            .map(|path| lessons.join(path)),
+    );
+    // the C side is compiled by build.rs, every src/ file it names belongs to the lesson
+    if source.contains("extern \"C\"") {
+        let build = source_tree().join("build.rs");
+        let script = fs::read_to_string(&build).unwrap_or_default();
+        files.extend(
+            script
+                .split('"')
+                .filter(|part| part.starts_with("src/"))
+                .map(|path| source_tree().join(path)),
+        );
+        files.push(build);
+    }
+    for exercise in lesson_exercises(name) {
+        let dir = source_tree().join("src/exercises").join(exercise.name);
+        files.push(dir.join("starter.rs"));
+        files.push(dir.join("tests.rs"));
+        files.push(exercise.solution_path());
+    }
+    files
+}
+
+// cargo run -- <args> with the playground of the source tree
+fn cargo_run(args: &[&str]) -> Result<bool, String> {
+    // CARGO is set when we were started by cargo run, like RUSTC in toolchain
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let status = Command::new(cargo)
+        .args(["run", "--quiet", "--manifest-path"])
+        .arg(source_tree().join("Cargo.toml"))
+        .arg("--")
+        .args(args)
+        .status()
+        .map_err(|e| format!("cannot run cargo: {e}"))?;
+    Ok(status.success())
+}
+
+fn rebuild_and_run(lesson: &str) -> Result<(), String> {
+    if !cargo_run(&["run", lesson])? {
+        return Err(format!("the lesson `{lesson}` doesn't build or run"));
+    }
+    // the fresh build has the current hidden tests, so the check runs there too
+    let mut failed = Vec::new();
+    for exercise in lesson_exercises(lesson) {
+        if exercise.solution_path().exists() {
+            println!();
+            if !cargo_run(&["check", exercise.name])? {
+                failed.push(exercise.name);
+            }
+        }
+    }
+    match failed[..] {
+        [] => Ok(()),
+        _ => Err(format!("not passing yet: {}", failed.join(", "))),
+    }
+}
+
+// a missing file counts as a state too, it may be saved again
+fn modified(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    files
+        .iter()
+        .map(|file| fs::metadata(file).and_then(|meta| meta.modified()).ok())
+        .collect()
+}
+
+fn clear_screen() {
+    print!("\x1b[2J\x1b[H");
+}
+
+// watch <lesson | exercise> [solution file]
+pub fn command(args: &[String]) -> Result<(), String> {
+    let name = args
+        .first()
+        .ok_or("usage: watch <lesson | exercise> [solution file]")?;
+    let target = if let Some(lesson) = lessons::find(name) {
+        if !source_tree().join("src/lessons").is_dir() {
+            return Err(format!(
+                "the lesson sources are not in {}, watching lessons needs the source tree",
+                source_tree().display()
+            ));
+        }
+        Target::Lesson(lesson.name)
+    } else {
+        let exercise = exercises::find(name).map_err(|_| {
+            format!("`{name}` is neither a lesson nor an exercise, see `localplayground list`")
+        })?;
+        let solution = args
+            .get(1)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| exercise.solution_path());
+        Target::Exercise(exercise.name, solution)
+    };
+
+    let files = target.files();
+    let shown: Vec<String> = files
+        .iter()
+        .map(|file| file.display().to_string())
+        .collect();
+    let mut last = modified(&files);
+    loop {
+        clear_screen();
+        target.run();
+        println!("\nwatching {}, ctrl-c to stop", shown.join(", "));
+
+        // wait for a change, then until the burst of writes is over
+        while modified(&files) == last {
+            thread::sleep(POLL);
+        }
+        loop {
+            let current = modified(&files);
+            thread::sleep(QUIET);
+            if modified(&files) == current {
+                last = current;
+                break;
+            }
+        }
+    }
+}